@@ -59,19 +59,32 @@
 //! * Two main structures for doing the free-arithmetic over some type
 //! * Traits for specifying the rules for arithmetic
 //! * Type aliases for particular combinations of construction and rules
+//! * A [Render] trait for typesetting the structures as configurable plain text, LaTeX, or MathML
+//! * Polynomial types, like [UniPoly] and the multivariate [Polynomial], including:
+//!   * the usual algorithms of polynomial arithmetic, factorization, real root isolation,
+//!     interpolation, [linear recurrences](LinearRecurrence), and [Gröbner bases](GroebnerBasis)
+//!   * exact [Rational] and [prime field](Zp) coefficients
+//!   * [rational functions](RationalFunction) and [Laurent polynomials](LaurentPolynomial)
+//!   * [quotient rings](QuotientRing) for number fields and `GF(p^n)`
 //!
 //!Specifically:
 //! * [MonoidalString] constructs free-multiplying structures over a type `T` using an order-dependent
 //!   internal representation with a [`Vec<T>`](Vec) that determines its multiplication rule using an
 //!   implementor of the trait [MonoidRule]. Aliases of this struct include [FreeMonoid] and [FreeGroup].
 //! * [ModuleString] constructs types consisting of terms of type `T` with scalars from
-//!   some additive type `R` stored with an order independent [HashMap]. This grants all [ModuleString]'s
+//!   some additive type `R` stored in a [TermMap]: by default an order independent [HashMap],
+//!   though a [HashMap] with a custom [BuildHasher](std::hash::BuildHasher), an ordered [BTreeMap],
+//!   or, for terms indexed by [usize], a [DenseMap] can be used instead. This grants all [ModuleString]'s
 //!   an addition operation by adding the coeffients of like terms, and a free-multiplication can
 //!   be included using an optional [AlgebraRule] parameter. Aliases of this struct include
 //!   [FreeModule] and [FreeAlgebra].
 //!
 //!For more information, see the respective structs
 //!
+//!# Features
+//!
+//! * `rayon`: Splits large [ModuleString] products across threads and allows [ModuleString]'s to be
+//!   collected from and summed over parallel iterators
 //!
 
 #![feature(specialization)]
@@ -81,6 +94,7 @@
 #[macro_use] extern crate derivative;
 extern crate num_traits;
 extern crate maths_traits;
+#[cfg(feature="rayon")] extern crate rayon;
 
 pub use self::monoid::*;
 pub use self::module::*;
@@ -91,7 +105,7 @@ use num_traits::Pow;
 
 use std::marker::PhantomData;
 use std::borrow::Borrow;
-use std::collections::HashMap;
+use std::collections::{HashMap, BTreeMap};
 use std::hash::Hash;
 use std::fmt::{Display, Formatter};
 
@@ -138,8 +152,39 @@ impl<T:Zero> IsZero for T {
     default fn _zero() -> Option<Self> { Some(Self::zero()) }
 }
 
+trait IsOne: Sized {
+    fn _is_one(&self) -> bool;
+}
+impl<T> IsOne for T {
+    default fn _is_one(&self) -> bool { false }
+}
+impl<T:One+PartialEq> IsOne for T {
+    default fn _is_one(&self) -> bool { self.is_one() }
+}
+
+trait IsNegative: Sized {
+    ///Returns the negation of `self` if it is less than zero
+    fn _abs_if_negative(&self) -> Option<Self>;
+}
+impl<T> IsNegative for T {
+    default fn _abs_if_negative(&self) -> Option<Self> { None }
+}
+impl<T:Zero+PartialOrd+Neg<Output=T>+Clone> IsNegative for T {
+    default fn _abs_if_negative(&self) -> Option<Self> {
+        if *self < T::zero() { Some(-self.clone()) } else { None }
+    }
+}
+
 pub use self::specifics::*;
 mod specifics;
 
+pub use self::polynomial::*;
+mod polynomial;
+
+pub use self::render::*;
+
 pub mod monoid;
 pub mod module;
+pub mod render;
+
+#[cfg(feature="rayon")] mod parallel;
@@ -6,7 +6,10 @@
 
 use super::*;
 
-use std::collections::hash_map;
+use std::collections::{hash_map, btree_map};
+use std::{slice, vec};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hasher, BuildHasher};
 
 ///
 ///Creates free-arithmetic constructions based upon order-invariant addition of terms of type `C` with
@@ -15,8 +18,8 @@ use std::collections::hash_map;
 ///# Basic Construction
 ///
 ///Given type parameters `R`, `T`, and `A`, the construction goes as follows:
-/// * Internally, each instance contains a [map](HashMap) from instances of `T` to coefficients from `R`
-/// * Addition is then implemented by merging the arguments' [HashMaps](HashMap) where repeated terms
+/// * Internally, each instance contains a [map](TermMap) from instances of `T` to coefficients from `R`
+/// * Addition is then implemented by merging the arguments' maps where repeated terms
 ///  have their coefficients added together
 /// * Multiplication with `R` values is applied by each term's coefficient with the given scalar
 /// * Multiplication between terms is performed by multiplying the coefficients and apply the rule
@@ -42,6 +45,63 @@ use std::collections::hash_map;
 /// * [Distributive] whenever `R` is
 /// * [Extend], [FromIterator], and [Sum] all are implemented using repeated addition.
 ///
+///# Storage
+///
+///The map of terms used internally is given by the optional type parameter `S`. By default, this is
+///a [HashMap], requiring `T` to implement [Hash] and [Eq], but any [TermMap] can be used in its place.
+///In particular, using a [BTreeMap] only requires `T` to be [Ord] and gives iteration in order of
+///the terms and access to the [leading](ModuleString::leading_term) and
+///[trailing](ModuleString::trailing_term) terms in `O(log n)` time.
+///
+///Likewise, a [HashMap] with any [BuildHasher] can be used, so if the default SipHash is too slow
+///for small terms, a faster (non-cryptographic) hasher can be swapped in instead:
+///
+///```
+///use std::collections::HashMap;
+///use std::hash::{Hasher, BuildHasherDefault};
+///use maths_traits::algebra::Zero;
+///use free_algebra::ModuleString;
+///
+///#[derive(Default)]
+///struct Fnv(u64);
+///
+///impl Hasher for Fnv {
+///    fn finish(&self) -> u64 { self.0 }
+///    fn write(&mut self, bytes: &[u8]) {
+///        for b in bytes { self.0 = (self.0 ^ *b as u64).wrapping_mul(0x100000001b3); }
+///    }
+///}
+///
+///type FnvFreeModule<R,T> = ModuleString<R,T,(),HashMap<T,R,BuildHasherDefault<Fnv>>>;
+///
+///let p = FnvFreeModule::zero() + (3.5, 'x') + (2.0, 'y');
+///let q = FnvFreeModule::zero() + (1.0, 'x') - (2.0, 'y');
+///
+///assert_eq!((p + q).iter().collect::<Vec<_>>(), [(&4.5, &'x')]);
+///
+///```
+///
+///For terms that are indices of some small basis, a [DenseMap] stores the coefficients in a [Vec]
+///instead (see [DenseFreeModule]), and [into_storage](ModuleString::into_storage) converts between any two
+///kinds of storage.
+///
+///Alternatively, for an ordered backend:
+///
+///```
+///use std::collections::BTreeMap;
+///use maths_traits::algebra::Zero;
+///use free_algebra::{ModuleString, MulRule};
+///
+///type OrderedMonoidRing<R,M> = ModuleString<R,M,MulRule,BTreeMap<M,R>>;
+///
+///let p = OrderedMonoidRing::zero() + (2.0, 'y') + (1.0, 'z') + (3.5, 'x');
+///
+///assert_eq!(p.iter().collect::<Vec<_>>(), [(&3.5, &'x'), (&2.0, &'y'), (&1.0, &'z')]);
+///assert_eq!(p.leading_term(), Some((&1.0, &'z')));
+///assert_eq!(p.trailing_term(), Some((&3.5, &'x')));
+///
+///```
+///
 ///# A note on [IterMut]
 ///
 ///The method [ModuleString.iter_mut()] *will* give an valid iterator over mutable references to each
@@ -56,20 +116,26 @@ use std::collections::hash_map;
 #[derive(Derivative)]
 #[derivative(Clone(clone_from="true"))]
 #[derivative(Default(bound=""))]
-#[derivative(PartialEq, Eq, Hash)]
+#[derivative(PartialEq, Eq)]
 #[derivative(Debug="transparent")]
-pub struct ModuleString<R,T:Hash+Eq,A:?Sized> {
-    #[derivative(Hash(bound="HashMap<T,R>:Hash"))]
-    #[derivative(Default(value="HashMap::with_capacity(0)"))]
-    terms: HashMap<T,R>,
+pub struct ModuleString<R,T,A:?Sized,S:TermMap<T,R>=HashMap<T,R>> {
+    #[derivative(Default(value="S::default()"))]
+    terms: S,
 
-    #[derivative(PartialEq="ignore", Hash="ignore")]
+    #[derivative(PartialEq="ignore")]
     #[derivative(Debug="ignore")]
-    rule: PhantomData<Box<A>>
+    rule: PhantomData<(T,R,Box<A>)>
 }
 
 ///
-///Formats the [ModuleString] as a sequence of factors separated by `+`'s
+///Formats the [ModuleString] as a sum of terms separated by `+`'s and `-`'s
+///
+///To keep the output stable, the terms are always written in increasing order using `T`'s [Ord]
+///impl or, if `T` has no ordering, using the order of the terms' formatting. In addition:
+/// * Coefficients of `1` are omitted, and terms with negative coefficients are subtracted
+/// * Coefficients and terms are only wrapped in parentheses when they are sums themselves
+/// * A precision, like `{:.2}`, is applied to the coefficients but not the terms
+/// * If the "alternate" flag `#` is used, the `*`'s between coefficients and terms are dropped
 ///
 ///# Examples
 ///```
@@ -89,71 +155,82 @@ pub struct ModuleString<R,T:Hash+Eq,A:?Sized> {
 ///p += (2.5, one);
 ///assert_eq!(format!("{}", p), "3.5");
 ///
-///p += x;
-///assert!(format!("{}", p) == "(3.5 + x)" || format!("{}", p) == "(x + 3.5)");
+///p += x.clone();
+///assert_eq!(format!("{}", p), "3.5 + x");
+///
+///p *= (1.0, y.clone());
+///assert_eq!(format!("{}", p), "x*y + 3.5*y");
+///assert_eq!(format!("{:#}", p), "xy + 3.5y");
 ///
-///p *= (1.0, y);
-///assert!(format!("{}", p) == "(3.5*y + x*y)" || format!("{}", p) == "(x*y + 3.5*y)");
-///assert!(format!("{:#}", p) == "(3.5y + xy)" || format!("{:#}", p) == "(xy + 3.5y)");
+///p -= (1.0/3.0, &x*&y);
+///assert_eq!(format!("{:.3}", p), "0.667*x*y + 3.500*y");
+///
+///p -= (3.5, y);
+///assert_eq!(format!("{:.3}", p), "0.667*x*y");
+///assert_eq!(format!("{:.3}", -p), "-0.667*x*y");
+///
+///```
 ///
 ///```
+///use maths_traits::algebra::One;
+///use free_algebra::{FreeModule, FreeAlgebra, FreeMonoid};
+///
+///let p = FreeModule::<i32,_>::from('a') - 'b' - (3, 'c');
+///assert_eq!(format!("{}", p), "a - b - 3*c");
 ///
-impl<R:Display,T:Hash+Eq+Display,A:?Sized> Display for ModuleString<R,T,A> {
+///let a:FreeMonoid<_> = 'a'.into();
+///let q = FreeModule::<_,char>::from((FreeAlgebra::<i32,_>::one() - a, 'x')) + 'y';
+///assert_eq!(format!("{}", q), "(1 - a)*x + y");
+///
+///```
+///
+impl<R:Display,T:Display,A:?Sized,S:TermMap<T,R>> Display for ModuleString<R,T,A,S> {
     fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+
+        //writes a coefficient or term using the flags of f and adds parentheses around sums
+        fn write_factor<X:Display>(f: &mut Formatter, x:&X, coeff:bool) -> ::std::fmt::Result {
+            let (l, r) = if x.is_compound() { ("(", ")") } else { ("", "") };
+            match (f.alternate(), f.precision().filter(|_| coeff)) {
+                (false, None) => write!(f, "{}{}{}", l, x, r),
+                (true, None) => write!(f, "{}{:#}{}", l, x, r),
+                (false, Some(p)) => write!(f, "{}{:.*}{}", l, p, x, r),
+                (true, Some(p)) => write!(f, "{}{:#.*}{}", l, p, x, r),
+            }
+        }
+
         if self.len() == 0 {
             //try to print 0 as formatted by R
-            match R::_zero() {
-                Some(zero) => write!(f, "{}", zero)?,
-                None => write!(f, "{}", 0)?,
+            return match R::_zero() {
+                Some(zero) => write_factor(f, &zero, true),
+                None => write!(f, "{}", 0),
+            };
+        }
+
+        //print every term in a sum
+        for (i, (r,t)) in self.sorted_terms().into_iter().enumerate() {
+            let t: &T = t.borrow();
+
+            //add the sign to the previous term, using subtraction for negative coefficients
+            let abs = (*r)._abs_if_negative();
+            match (i, abs.is_some()) {
+                (0, false) => (),
+                (0, true) => write!(f, "-")?,
+                (_, false) => write!(f, " + ")?,
+                (_, true) => write!(f, " - ")?,
             }
-        } else {
-            //put parens around sums
-            if self.len() > 1 { write!(f, "(")?; }
-
-            //use specialization to determine if a T is one
-            trait IsTOne<R,T> { fn _is_t_one(t:&T) -> bool; }
-            impl<R,T,A:?Sized> IsTOne<R,T> for A { default fn _is_t_one(_:&T) -> bool {false} }
-            impl<R,T,A:UnitalAlgebraRule<R,T>+?Sized> IsTOne<R,T> for A { fn _is_t_one(t:&T) -> bool {A::is_one(t)} }
-
-            //use specialization to determine if a R is one
-            trait IsOne { fn _is_one(&self) -> bool; }
-            impl<R> IsOne for R { default fn _is_one(&self) -> bool {false} }
-            impl<R:One+PartialEq> IsOne for R { fn _is_one(&self) -> bool { self.is_one() } }
-
-            //print every term in a sum
-            let mut first = true;
-            for (r,t) in self.iter() {
-
-                //add the plus sign to the previous sum
-                if !first {
-                    write!(f, " + ")?;
-                } else {
-                    first = false;
-                }
+            let r = abs.as_ref().unwrap_or(r);
 
-                //if the term isn't one, write the term
-                if !<A as IsTOne<R,T>>::_is_t_one(t) {
-                    //if the coeffient is one, don't write it
-                    if (*r)._is_one() {
-                        if f.alternate() {
-                            write!(f, "{:#}", t)?;
-                        } else {
-                            write!(f, "{}", t)?;
-                        }
-                    } else {
-                        if f.alternate() {
-                            write!(f, "{:#}{:#}", r, t)?;
-                        } else {
-                            write!(f, "{}*{}", r, t)?;
-                        }
-                    }
-                } else {
-                    write!(f, "{}", r)?;
+            //if the term isn't one, write the term
+            if !<A as IsTOne<R,T>>::_is_t_one(t) {
+                //if the coeffient is one, don't write it
+                if !(*r)._is_one() {
+                    write_factor(f, r, true)?;
+                    if !f.alternate() { write!(f, "*")?; }
                 }
+                write_factor(f, t, false)?;
+            } else {
+                write_factor(f, r, true)?;
             }
-
-            //put parens around sums
-            if self.len() > 1 { write!(f, ")")?; }
         }
 
         //success
@@ -161,29 +238,84 @@ impl<R:Display,T:Hash+Eq+Display,A:?Sized> Display for ModuleString<R,T,A> {
     }
 }
 
-///Iterates over references to the terms and coefficients of a [ModuleString]
-pub type Iter<'a,R,T> = Map<hash_map::Iter<'a,T,R>, fn((&'a T,&'a R)) -> (&'a R,&'a T)>;
+///
+///Hashes the terms and coefficients in a way that is independent of the order in which they are stored
+///
+///Since the order of iteration of a [HashMap] depends on the order of insertion and on the state
+///of its hasher, each term is hashed separately with a fixed hasher and the results are combined
+///commutatively. This way, equal [ModuleString]'s always have equal hashes, allowing them to be used
+///as the keys of other maps or even as the terms or coefficients of another [ModuleString].
+///
+///# Examples
+///```
+///use maths_traits::algebra::Zero;
+///use free_algebra::FreeModule;
+///use std::collections::HashSet;
+///
+///let p = FreeModule::<i32,_>::zero() + (1, 'x') + (2, 'y') + (3, 'z');
+///let q = FreeModule::<i32,_>::zero() + (3, 'z') + (2, 'y') + (1, 'x');
+///
+///let mut set = HashSet::new();
+///set.insert(p.clone());
+///assert!(set.contains(&q));
+///
+///let a: FreeModule<i32, FreeModule<i32,char>> = FreeModule::from(p.clone()) + (2, q.clone());
+///assert_eq!(a.get(&q), 3);
+///
+///```
+///
+impl<R:Hash,T:Hash,A:?Sized,S:TermMap<T,R>> Hash for ModuleString<R,T,A,S> {
+    fn hash<H:Hasher>(&self, state: &mut H) {
+        let sum = self.iter().fold(0u64, |sum, (r,t)| {
+            let mut hasher = DefaultHasher::new();
+            (r,t.borrow()).hash(&mut hasher);
+            sum.wrapping_add(hasher.finish())
+        });
+        state.write_usize(self.len());
+        state.write_u64(sum);
+    }
+}
+
+//use specialization to pick the order terms are displayed in
+pub(crate) trait SortTerms: Display { fn _sort_terms<R,Q:Borrow<Self>>(terms: &mut [(&R,Q)]); }
+impl<T:Display> SortTerms for T {
+    default fn _sort_terms<R,Q:Borrow<Self>>(terms: &mut [(&R,Q)]) { terms.sort_by_cached_key(|(_,t)| t.borrow().to_string()) }
+}
+impl<T:Display+Ord> SortTerms for T {
+    default fn _sort_terms<R,Q:Borrow<Self>>(terms: &mut [(&R,Q)]) { terms.sort_by(|(_,t1),(_,t2)| t1.borrow().cmp(t2.borrow())) }
+}
+
+//use specialization to determine if a T is one
+pub(crate) trait IsTOne<R,T> { fn _is_t_one(t:&T) -> bool; }
+impl<R,T,A:?Sized> IsTOne<R,T> for A { default fn _is_t_one(_:&T) -> bool {false} }
+impl<R,T,A:UnitalAlgebraRule<R,T>+?Sized> IsTOne<R,T> for A { fn _is_t_one(t:&T) -> bool {A::is_one(t)} }
+
+///Iterates over the terms and references to the coefficients of a [ModuleString]
+pub type Iter<'a,R,T,S=HashMap<T,R>> = Map<
+    <S as TermMap<T,R>>::Iter<'a>,
+    fn((<S as TermMap<T,R>>::Term<'a>,&'a R)) -> (&'a R,<S as TermMap<T,R>>::Term<'a>)
+>;
 
 ///Iterates over terms and coefficients of a [ModuleString]
-pub type IntoIter<R,T> = Map<hash_map::IntoIter<T,R>, fn((T,R)) -> (R,T)>;
+pub type IntoIter<R,T,S=HashMap<T,R>> = Map<<S as IntoIterator>::IntoIter, fn((T,R)) -> (R,T)>;
 
 ///
 ///Iterates over mutable references to the terms and coefficients of a [ModuleString]
 ///
-///Note that this causes a reallocation of the internal [HashMap] since it's possible that an
+///Note that this causes a reallocation of the internal [TermMap] since it's possible that an
 ///element mutation could create an illegal state if not reconstructed from the sums of the mutated
 ///terms. (For example, one could easily mutate two terms to have the same `T` value and thus
 ///potentially overwrite the coeffient of one of them if not handled properly)
 ///
-pub struct IterMut<'a, R:AddAssign, T:Hash+Eq, A:?Sized> {
-    dest_ref: &'a mut ModuleString<R,T,A>,
+pub struct IterMut<'a, R:AddAssign, T, A:?Sized, S:TermMap<T,R>=HashMap<T,R>> {
+    dest_ref: &'a mut ModuleString<R,T,A,S>,
     next: Option<(R,T)>,
-    iter: IntoIter<R,T>
+    iter: IntoIter<R,T,S>
 }
 
-impl<'a,T:Hash+Eq,R:AddAssign,A:?Sized> FusedIterator for IterMut<'a,R,T,A> {}
-impl<'a,T:Hash+Eq,R:AddAssign,A:?Sized> ExactSizeIterator for IterMut<'a,R,T,A> {}
-impl<'a,T:Hash+Eq,R:AddAssign,A:?Sized> Iterator for IterMut<'a,R,T,A> {
+impl<'a,T,R:AddAssign,A:?Sized,S:TermMap<T,R>> FusedIterator for IterMut<'a,R,T,A,S> {}
+impl<'a,T,R:AddAssign,A:?Sized,S:TermMap<T,R>> ExactSizeIterator for IterMut<'a,R,T,A,S> where S::IntoIter:ExactSizeIterator {}
+impl<'a,T,R:AddAssign,A:?Sized,S:TermMap<T,R>> Iterator for IterMut<'a,R,T,A,S> {
     type Item = (&'a mut R, &'a mut T);
     fn next(&mut self) -> Option<Self::Item> {
         self.next.take().map(|t| *self.dest_ref += t);
@@ -197,37 +329,37 @@ impl<'a,T:Hash+Eq,R:AddAssign,A:?Sized> Iterator for IterMut<'a,R,T,A> {
     fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
 }
 
-impl<'a,T:Hash+Eq,R:AddAssign,A:?Sized> Drop for IterMut<'a,R,T,A> {
+impl<'a,T,R:AddAssign,A:?Sized,S:TermMap<T,R>> Drop for IterMut<'a,R,T,A,S> {
     fn drop(&mut self) {
         loop { if let None = self.next() {break;} }
     }
 }
 
-impl<T:Hash+Eq,R:One,A:?Sized> From<T> for ModuleString<R,T,A> {
+impl<T,R:One,A:?Sized,S:TermMap<T,R>> From<T> for ModuleString<R,T,A,S> {
     #[inline] fn from(t:T) -> Self {(R::one(),t).into()}
 }
 
-impl<T:Hash+Eq,R:One,A:?Sized> From<(R, T)> for ModuleString<R,T,A> {
+impl<T,R:One,A:?Sized,S:TermMap<T,R>> From<(R, T)> for ModuleString<R,T,A,S> {
     #[inline]
     fn from((r,t):(R,T)) -> Self {
-        let mut m = HashMap::new();
+        let mut m = S::default();
         m.insert(t, r);
         ModuleString{terms:m, rule:PhantomData}
     }
 }
 
-impl<T:Hash+Eq,R,A:?Sized,I> Index<I> for ModuleString<R,T,A> where HashMap<T,R>:Index<I> {
-    type Output = <HashMap<T,R> as Index<I>>::Output;
+impl<T,R,A:?Sized,I,S:TermMap<T,R>+Index<I>> Index<I> for ModuleString<R,T,A,S> {
+    type Output = S::Output;
     #[inline] fn index(&self, i:I) -> &Self::Output {&self.terms[i]}
 }
 
-impl<T:Hash+Eq,R,A:?Sized> IntoIterator for ModuleString<R,T,A> {
+impl<T,R,A:?Sized,S:TermMap<T,R>> IntoIterator for ModuleString<R,T,A,S> {
     type Item = (R,T);
-    type IntoIter = IntoIter<R,T>;
-    #[inline] fn into_iter(self) -> IntoIter<R,T> { self.terms.into_iter().map(|(t,r)| (r,t)) }
+    type IntoIter = IntoIter<R,T,S>;
+    #[inline] fn into_iter(self) -> IntoIter<R,T,S> { self.terms.into_iter().map(|(t,r)| (r,t)) }
 }
 
-impl<T:Hash+Eq,R,A:?Sized> ModuleString<R,T,A> {
+impl<T,R,A:?Sized,S:TermMap<T,R>> ModuleString<R,T,A,S> {
 
     ///
     ///Returns the number of terms in this module element
@@ -268,8 +400,8 @@ impl<T:Hash+Eq,R,A:?Sized> ModuleString<R,T,A> {
     ///
     ///```
     ///
-    pub fn get_ref<Q:Hash+Eq>(&self, t: &Q) -> Option<&R> where T:Borrow<Q> {
-        self.terms.get(t)
+    pub fn get_ref<Q:?Sized>(&self, t: &Q) -> Option<&R> where S:TermLookup<Q,R> {
+        self.terms.lookup(t)
     }
 
     ///
@@ -289,12 +421,17 @@ impl<T:Hash+Eq,R,A:?Sized> ModuleString<R,T,A> {
     ///
     ///```
     ///
-    pub fn get<Q:Hash+Eq>(&self, t: &Q) -> R where T:Borrow<Q>, R:Zero+Clone {
-        self.terms.get(t).map_or_else(|| R::zero(), |r| r.clone())
+    pub fn get<Q:?Sized>(&self, t: &Q) -> R where S:TermLookup<Q,R>, R:Zero+Clone {
+        self.terms.lookup(t).map_or_else(|| R::zero(), |r| r.clone())
     }
 
-    ///Produces an iterator over references to the terms and references in this element
-    pub fn iter<'a>(&'a self) -> Iter<'a,R,T> { self.terms.iter().map(|(t,r)| (r,t)) }
+    ///
+    ///Produces an iterator over references to the coefficients and terms in this element
+    ///
+    ///The terms are given as references for the [HashMap] and [BTreeMap] storages, and by value
+    ///for a [DenseMap], which doesn't store its indices
+    ///
+    pub fn iter<'a>(&'a self) -> Iter<'a,R,T,S> { self.terms.iter().map(|(t,r)| (r,t)) }
 
     ///
     ///Produces an iterator over mutable references to the terms and references in this element
@@ -316,12 +453,31 @@ impl<T:Hash+Eq,R,A:?Sized> ModuleString<R,T,A> {
     ///
     ///```
     ///
-    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a,R,T,A> where R:AddAssign {
-        let mut temp = Self { terms: HashMap::with_capacity(self.len()), rule:PhantomData };
+    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a,R,T,A,S> where R:AddAssign {
+        let mut temp = Self { terms: S::with_capacity(self.len()), rule:PhantomData };
         ::std::mem::swap(self, &mut temp);
         IterMut { dest_ref: self, next: None, iter: temp.into_iter() }
     }
 
+    ///
+    ///Moves the terms into a different kind of [TermMap]
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::Zero;
+    ///use free_algebra::{FreeModule, OrderedFreeModule};
+    ///
+    ///let p = FreeModule::zero() + (3.5, 'z') + (2.0, 'y') + (1.0, 'x');
+    ///let q: OrderedFreeModule<_,_> = p.into_storage();
+    ///
+    ///assert_eq!(q.iter().collect::<Vec<_>>(), [(&1.0, &'x'), (&2.0, &'y'), (&3.5, &'z')]);
+    ///
+    ///```
+    ///
+    pub fn into_storage<S2:TermMap<T,R>>(self) -> ModuleString<R,T,A,S2> {
+        ModuleString { terms: self.terms.into_iter().collect(), rule: PhantomData }
+    }
+
     ///
     ///Computes the algebraic commutator `[a,b] = a*b - b*a`
     ///
@@ -367,7 +523,7 @@ impl<T:Hash+Eq,R,A:?Sized> ModuleString<R,T,A> {
 
 }
 
-impl<T:Hash+Eq,R,A:?Sized> ModuleString<R,T,A> {
+impl<T,R,A:?Sized,S:TermMap<T,R>> ModuleString<R,T,A,S> {
 
     fn insert_term<F:Fn(R)->R>(&mut self, rhs:(R,T), f:F) where R:AddAssign{
         if !rhs.0._is_zero() {
@@ -389,14 +545,21 @@ impl<T:Hash+Eq,R,A:?Sized> ModuleString<R,T,A> {
     }
 
     ///removes all terms with a coeffient of zero
-    fn clean(&mut self) { self.terms.retain(|_,r| !r._is_zero()); }
+    pub(crate) fn clean(&mut self) { self.terms.retain(|_,r| !(*r)._is_zero()); }
+
+    ///lists the terms by the ordering of `T` if it has one and by their formatting if it doesn't
+    pub(crate) fn sorted_terms(&self) -> Vec<(&R,S::Term<'_>)> where T:Display {
+        let mut terms: Vec<_> = self.iter().collect();
+        T::_sort_terms(&mut terms);
+        terms
+    }
 }
 
-impl<T:Hash+Eq,R:AddAssign,A:?Sized> Extend<(R,T)> for ModuleString<R,T,A> {
+impl<T,R:AddAssign,A:?Sized,S:TermMap<T,R>> Extend<(R,T)> for ModuleString<R,T,A,S> {
     fn extend<I:IntoIterator<Item=(R,T)>>(&mut self, iter:I) { self.insert(iter, |r| r) }
 }
 
-impl<T:Hash+Eq,R:AddAssign,A:?Sized> FromIterator<(R,T)> for ModuleString<R,T,A> {
+impl<T,R:AddAssign,A:?Sized,S:TermMap<T,R>> FromIterator<(R,T)> for ModuleString<R,T,A,S> {
     fn from_iter<I:IntoIterator<Item=(R,T)>>(iter:I) -> Self {
         let mut from = Self::default();
         from.extend(iter);
@@ -404,26 +567,370 @@ impl<T:Hash+Eq,R:AddAssign,A:?Sized> FromIterator<(R,T)> for ModuleString<R,T,A>
     }
 }
 
-impl<T:Hash+Eq,R:AddAssign+One,A:?Sized> FromIterator<T> for ModuleString<R,T,A> {
+impl<T,R:AddAssign+One,A:?Sized,S:TermMap<T,R>> FromIterator<T> for ModuleString<R,T,A,S> {
     fn from_iter<I:IntoIterator<Item=T>>(iter:I) -> Self {
         Self::from_iter(iter.into_iter().map(|t| (R::one(), t)))
     }
 }
 
-impl<T:Hash+Eq,R:AddAssign,A:?Sized> FromIterator<Self> for ModuleString<R,T,A> {
+impl<T,R:AddAssign,A:?Sized,S:TermMap<T,R>> FromIterator<Self> for ModuleString<R,T,A,S> {
     fn from_iter<I:IntoIterator<Item=Self>>(iter:I) -> Self {
         Self::from_iter(iter.into_iter().flatten())
     }
 }
 
-impl<T:Hash+Eq,R:AddAssign,A:?Sized,K> Sum<K> for ModuleString<R,T,A> where Self:FromIterator<K> {
+impl<T,R:AddAssign,A:?Sized,K,S:TermMap<T,R>> Sum<K> for ModuleString<R,T,A,S> where Self:FromIterator<K> {
     fn sum<I:Iterator<Item=K>>(iter:I) -> Self { Self::from_iter(iter) }
 }
 
-impl<T:Hash+Eq,R,A:?Sized,K> Product<K> for ModuleString<R,T,A> where Self:Mul<K,Output=Self>+One {
+impl<T,R,A:?Sized,K,S:TermMap<T,R>> Product<K> for ModuleString<R,T,A,S> where Self:Mul<K,Output=Self>+One {
     fn product<I:Iterator<Item=K>>(iter:I) -> Self { iter.into_iter().fold(Self::one(), |m,k| m*k) }
 }
 
+///
+///A map from terms to coefficients that can be used as the internal storage of a [ModuleString]
+///
+///This is implemented for [HashMap] and [BTreeMap], but other implementors can be used as well,
+///provided that they behave like a map: each term is stored at most once, and inserting
+///or removing a term does not affect any of the others.
+///
+pub trait TermMap<T,R>: Default + TermLookup<T,R> + IntoIterator<Item=(T,R)> + FromIterator<(T,R)> {
+
+    ///A term as given by [iter](TermMap::iter), either a reference to one stored in the map or its value
+    type Term<'a>: Borrow<T> where Self:'a, T:'a, R:'a;
+
+    ///Iterates over the terms and references to the coefficients in the map
+    type Iter<'a>: Iterator<Item=(Self::Term<'a>, &'a R)> where Self:'a, T:'a, R:'a;
+
+    ///Iterates over mutable references to the coefficients in the map
+    type ValuesMut<'a>: Iterator<Item=&'a mut R> where Self:'a, T:'a, R:'a;
+
+    ///Creates an empty map with space for at least `capacity` terms, if applicable
+    fn with_capacity(capacity: usize) -> Self { let _ = capacity; Self::default() }
+
+    ///The number of terms in the map
+    fn len(&self) -> usize;
+
+    ///Returns `true` if the map contains no terms
+    fn is_empty(&self) -> bool { self.len()==0 }
+
+    ///Retrieves a mutable reference to the coefficient of a term
+    fn get_mut(&mut self, term: &T) -> Option<&mut R>;
+
+    ///Sets the coefficient of a term, returning the previous one if it existed
+    fn insert(&mut self, term: T, coeff: R) -> Option<R>;
+
+    ///Removes a term from the map and returns its coefficient
+    fn remove(&mut self, term: &T) -> Option<R>;
+
+    ///Removes every term whose coefficient does not satisfy the given predicate
+    fn retain<F:FnMut(&T, &mut R) -> bool>(&mut self, f: F);
+
+    ///Produces an iterator over the terms and references to the coefficients in the map
+    fn iter(&self) -> Self::Iter<'_>;
+
+    ///Produces an iterator over mutable references to the coefficients in the map
+    fn values_mut(&mut self) -> Self::ValuesMut<'_>;
+
+}
+
+///
+///A [TermMap] that can look up coefficients using a borrowed form `Q` of the terms
+///
+///This is what allows, for instance, looking up a term of type [String] using a `&str`
+///
+pub trait TermLookup<Q:?Sized,R> {
+    ///Retrieves a reference to the coefficient of a term
+    fn lookup(&self, term: &Q) -> Option<&R>;
+}
+
+///
+///A [TermMap] that has a counterpart of the same kind storing coefficients of type `R2`
+///
+///This is what allows operations like [negation](Neg) to change the coefficient type of a [ModuleString]
+///
+pub trait WithCoeffs<T,R2> {
+    ///The same kind of map with coefficients of type `R2`
+    type Output: TermMap<T,R2>;
+}
+
+impl<T:Hash+Eq+Borrow<Q>,Q:Hash+Eq+?Sized,R,H:BuildHasher> TermLookup<Q,R> for HashMap<T,R,H> {
+    #[inline] fn lookup(&self, term: &Q) -> Option<&R> { self.get(term) }
+}
+
+impl<T:Hash+Eq,R,H:BuildHasher+Default> TermMap<T,R> for HashMap<T,R,H> {
+    type Term<'a> = &'a T where Self:'a, T:'a, R:'a;
+    type Iter<'a> = hash_map::Iter<'a,T,R> where Self:'a, T:'a, R:'a;
+    type ValuesMut<'a> = hash_map::ValuesMut<'a,T,R> where Self:'a, T:'a, R:'a;
+
+    #[inline] fn with_capacity(capacity: usize) -> Self { HashMap::with_capacity_and_hasher(capacity, H::default()) }
+    #[inline] fn len(&self) -> usize { HashMap::len(self) }
+    #[inline] fn get_mut(&mut self, term: &T) -> Option<&mut R> { HashMap::get_mut(self, term) }
+    #[inline] fn insert(&mut self, term: T, coeff: R) -> Option<R> { HashMap::insert(self, term, coeff) }
+    #[inline] fn remove(&mut self, term: &T) -> Option<R> { HashMap::remove(self, term) }
+    #[inline] fn retain<F:FnMut(&T, &mut R) -> bool>(&mut self, f: F) { HashMap::retain(self, f) }
+    #[inline] fn iter(&self) -> Self::Iter<'_> { HashMap::iter(self) }
+    #[inline] fn values_mut(&mut self) -> Self::ValuesMut<'_> { HashMap::values_mut(self) }
+}
+
+impl<T:Hash+Eq,R,R2,H:BuildHasher+Default> WithCoeffs<T,R2> for HashMap<T,R,H> {
+    type Output = HashMap<T,R2,H>;
+}
+
+impl<T:Ord+Borrow<Q>,Q:Ord+?Sized,R> TermLookup<Q,R> for BTreeMap<T,R> {
+    #[inline] fn lookup(&self, term: &Q) -> Option<&R> { self.get(term) }
+}
+
+impl<T:Ord,R> TermMap<T,R> for BTreeMap<T,R> {
+    type Term<'a> = &'a T where T:'a, R:'a;
+    type Iter<'a> = btree_map::Iter<'a,T,R> where T:'a, R:'a;
+    type ValuesMut<'a> = btree_map::ValuesMut<'a,T,R> where T:'a, R:'a;
+
+    #[inline] fn len(&self) -> usize { BTreeMap::len(self) }
+    #[inline] fn get_mut(&mut self, term: &T) -> Option<&mut R> { BTreeMap::get_mut(self, term) }
+    #[inline] fn insert(&mut self, term: T, coeff: R) -> Option<R> { BTreeMap::insert(self, term, coeff) }
+    #[inline] fn remove(&mut self, term: &T) -> Option<R> { BTreeMap::remove(self, term) }
+    #[inline] fn retain<F:FnMut(&T, &mut R) -> bool>(&mut self, f: F) { BTreeMap::retain(self, f) }
+    #[inline] fn iter(&self) -> Self::Iter<'_> { BTreeMap::iter(self) }
+    #[inline] fn values_mut(&mut self) -> Self::ValuesMut<'_> { BTreeMap::values_mut(self) }
+}
+
+impl<T:Ord,R,R2> WithCoeffs<T,R2> for BTreeMap<T,R> {
+    type Output = BTreeMap<T,R2>;
+}
+
+impl<T:Ord,R,A:?Sized> ModuleString<R,T,A,BTreeMap<T,R>> {
+
+    ///
+    ///Retrieves the greatest term and its coefficient in `O(log n)` time
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::Zero;
+    ///use free_algebra::OrderedFreeModule;
+    ///
+    ///let p = OrderedFreeModule::zero() + (3.5, 'x') + (2.0, 'y');
+    ///let q = OrderedFreeModule::<f32,char>::zero();
+    ///
+    ///assert_eq!(p.leading_term(), Some((&2.0, &'y')));
+    ///assert_eq!(q.leading_term(), None);
+    ///
+    ///```
+    ///
+    pub fn leading_term(&self) -> Option<(&R,&T)> { self.terms.last_key_value().map(|(t,r)| (r,t)) }
+
+    ///
+    ///Retrieves the least term and its coefficient in `O(log n)` time
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::Zero;
+    ///use free_algebra::OrderedFreeModule;
+    ///
+    ///let p = OrderedFreeModule::zero() + (3.5, 'x') + (2.0, 'y');
+    ///let q = OrderedFreeModule::<f32,char>::zero();
+    ///
+    ///assert_eq!(p.trailing_term(), Some((&3.5, &'x')));
+    ///assert_eq!(q.trailing_term(), None);
+    ///
+    ///```
+    ///
+    pub fn trailing_term(&self) -> Option<(&R,&T)> { self.terms.first_key_value().map(|(t,r)| (r,t)) }
+
+    ///
+    ///Removes and returns the greatest term and its coefficient in `O(log n)` time
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::Zero;
+    ///use free_algebra::OrderedFreeModule;
+    ///
+    ///let mut p = OrderedFreeModule::zero() + (3.5, 'x') + (2.0, 'y');
+    ///
+    ///assert_eq!(p.pop_leading_term(), Some((2.0, 'y')));
+    ///assert_eq!(p.pop_leading_term(), Some((3.5, 'x')));
+    ///assert_eq!(p.pop_leading_term(), None);
+    ///
+    ///```
+    ///
+    pub fn pop_leading_term(&mut self) -> Option<(R,T)> { self.terms.pop_last().map(|(t,r)| (r,t)) }
+
+    ///Removes and returns the least term and its coefficient in `O(log n)` time
+    pub fn pop_trailing_term(&mut self) -> Option<(R,T)> { self.terms.pop_first().map(|(t,r)| (r,t)) }
+
+}
+
+///
+///A [TermMap] from indices to coefficients that stores the coefficients contiguously
+///
+///This is meant for [ModuleString]'s over some small known basis indexed by [usize], like
+///[`DenseFreeModule<R>`](DenseFreeModule), where hashing or comparing every term is an unnecessary
+///expense. Each coefficient is stored in the slot given by its index, so the memory used is
+///proportional to the greatest index instead of to the number of terms, and the indices themselves
+///are never stored.
+///
+#[derive(Derivative)]
+#[derivative(Clone(clone_from="true"))]
+#[derivative(Default(bound=""))]
+#[derivative(PartialEq, Eq, Hash, Debug)]
+pub struct DenseMap<R> {
+    len: usize,
+    slots: Vec<Option<R>>
+}
+
+///Iterates over the indices and references to the coefficients of a [DenseMap]
+pub type DenseIter<'a,R> = FilterMap<Enumerate<slice::Iter<'a,Option<R>>>, fn((usize, &'a Option<R>)) -> Option<(usize,&'a R)>>;
+
+///Iterates over mutable references to the coefficients of a [DenseMap]
+pub type DenseValuesMut<'a,R> = Flatten<slice::IterMut<'a,Option<R>>>;
+
+///Iterates over the indices and coefficients of a [DenseMap]
+pub type DenseIntoIter<R> = FilterMap<Enumerate<vec::IntoIter<Option<R>>>, fn((usize, Option<R>)) -> Option<(usize,R)>>;
+
+impl<R> DenseMap<R> {
+    //removes the empty slots past the greatest index so that equal maps have equal storage
+    fn trim(&mut self) {
+        while let Some(None) = self.slots.last() { self.slots.pop(); }
+    }
+}
+
+impl<R> IntoIterator for DenseMap<R> {
+    type Item = (usize,R);
+    type IntoIter = DenseIntoIter<R>;
+    #[inline] fn into_iter(self) -> DenseIntoIter<R> {
+        self.slots.into_iter().enumerate().filter_map(|(i, s)| s.map(|r| (i, r)))
+    }
+}
+
+impl<R> FromIterator<(usize,R)> for DenseMap<R> {
+    fn from_iter<I:IntoIterator<Item=(usize,R)>>(iter:I) -> Self {
+        let mut map = Self::default();
+        for (i, r) in iter { map.insert(i, r); }
+        map
+    }
+}
+
+impl<R> TermLookup<usize,R> for DenseMap<R> {
+    #[inline] fn lookup(&self, term: &usize) -> Option<&R> {
+        self.slots.get(*term).and_then(|s| s.as_ref())
+    }
+}
+
+impl<R> TermMap<usize,R> for DenseMap<R> {
+    type Term<'a> = usize where R:'a;
+    type Iter<'a> = DenseIter<'a,R> where R:'a;
+    type ValuesMut<'a> = DenseValuesMut<'a,R> where R:'a;
+
+    #[inline] fn with_capacity(capacity: usize) -> Self { DenseMap { len: 0, slots: Vec::with_capacity(capacity) } }
+    #[inline] fn len(&self) -> usize { self.len }
+
+    #[inline] fn get_mut(&mut self, term: &usize) -> Option<&mut R> {
+        self.slots.get_mut(*term).and_then(|s| s.as_mut())
+    }
+
+    fn insert(&mut self, term: usize, coeff: R) -> Option<R> {
+        if term >= self.slots.len() { self.slots.resize_with(term+1, || None); }
+        let old = self.slots[term].replace(coeff);
+        if old.is_none() { self.len += 1; }
+        old
+    }
+
+    fn remove(&mut self, term: &usize) -> Option<R> {
+        let old = self.slots.get_mut(*term).and_then(|s| s.take());
+        if old.is_some() {
+            self.len -= 1;
+            self.trim();
+        }
+        old
+    }
+
+    fn retain<F:FnMut(&usize, &mut R) -> bool>(&mut self, mut f: F) {
+        for (i, slot) in self.slots.iter_mut().enumerate() {
+            if let Some(r) = slot {
+                if !f(&i, r) {
+                    *slot = None;
+                    self.len -= 1;
+                }
+            }
+        }
+        self.trim();
+    }
+
+    fn iter(&self) -> DenseIter<'_,R> {
+        self.slots.iter().enumerate().filter_map(|(i, s)| s.as_ref().map(|r| (i, r)))
+    }
+
+    fn values_mut(&mut self) -> DenseValuesMut<'_,R> { self.slots.iter_mut().flatten() }
+}
+
+impl<R,R2> WithCoeffs<usize,R2> for DenseMap<R> {
+    type Output = DenseMap<R2>;
+}
+
+impl<R,A:?Sized,S:TermMap<usize,R>> ModuleString<R,usize,A,S> {
+
+    ///
+    ///Converts this element to use a [DenseMap] to store its terms
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::Zero;
+    ///use free_algebra::{FreeModule, DenseFreeModule};
+    ///
+    ///let p = FreeModule::zero() + (3.5, 0) + (2.0, 2);
+    ///let q: DenseFreeModule<f32> = p.clone().into_dense();
+    ///
+    ///assert_eq!(q.to_vec(), [3.5, 0.0, 2.0]);
+    ///assert_eq!(q.into_sparse(), p);
+    ///
+    ///```
+    ///
+    pub fn into_dense(self) -> ModuleString<R,usize,A,DenseMap<R>> { self.into_storage() }
+
+}
+
+impl<R,A:?Sized> ModuleString<R,usize,A,DenseMap<R>> {
+
+    ///Converts this element to use a [HashMap] to store its terms
+    pub fn into_sparse(self) -> ModuleString<R,usize,A> { self.into_storage() }
+
+    ///
+    ///The number of coordinates needed to store this element densely
+    ///
+    ///ie, one more than the greatest index with a non-zero coefficient, or `0` if there are no terms
+    ///
+    pub fn dim(&self) -> usize { self.terms.slots.len() }
+
+    ///
+    ///Clones the coefficients into a [Vec] where the `i`th entry is the coefficient of index `i`
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::Zero;
+    ///use free_algebra::DenseFreeModule;
+    ///
+    ///let p = DenseFreeModule::from(vec![1, 0, -3, 0]);
+    ///let q = DenseFreeModule::zero() + (5, 3);
+    ///
+    ///assert_eq!(p.dim(), 3);
+    ///assert_eq!(p.to_vec(), [1, 0, -3]);
+    ///assert_eq!((p.clone() + q).to_vec(), [1, 0, -3, 5]);
+    ///assert_eq!(p.iter().collect::<Vec<_>>(), [(&1, 0), (&-3, 2)]);
+    ///
+    ///```
+    ///
+    pub fn to_vec(&self) -> Vec<R> where R:Zero+Clone {
+        self.terms.slots.iter().map(|s| s.as_ref().map_or_else(R::zero, |r| r.clone())).collect()
+    }
+
+}
+
+impl<R:AddAssign,A:?Sized> From<Vec<R>> for ModuleString<R,usize,A,DenseMap<R>> {
+    fn from(coords: Vec<R>) -> Self {
+        coords.into_iter().enumerate().map(|(i,r)| (r,i)).collect()
+    }
+}
+
 ///Dictates a rule for how to multiply terms in a [ModuleString]
 pub trait AlgebraRule<R,T> {
     ///Multiplies two terms together to produce another `T` and an optional coeffient
@@ -443,87 +950,87 @@ pub trait UnitalAlgebraRule<R,T>: AlgebraRule<R,T> {
     fn is_one(t:&T) -> bool;
 }
 
-impl<T:Hash+Eq,R:AddAssociative,A:?Sized> AddAssociative for ModuleString<R,T,A> {}
-impl<T:Hash+Eq,R:AddCommutative,A:?Sized> AddCommutative for ModuleString<R,T,A> {}
-impl<T:Hash+Eq,R:MulAssociative,A:?Sized+AssociativeAlgebraRule<R,T>> MulAssociative for ModuleString<R,T,A> {}
-impl<T:Hash+Eq,R:MulCommutative,A:?Sized+CommutativeAlgebraRule<R,T>> MulCommutative for ModuleString<R,T,A> {}
+impl<T,R:AddAssociative,A:?Sized,S:TermMap<T,R>> AddAssociative for ModuleString<R,T,A,S> {}
+impl<T,R:AddCommutative,A:?Sized,S:TermMap<T,R>> AddCommutative for ModuleString<R,T,A,S> {}
+impl<T,R:MulAssociative,A:?Sized+AssociativeAlgebraRule<R,T>,S:TermMap<T,R>> MulAssociative for ModuleString<R,T,A,S> {}
+impl<T,R:MulCommutative,A:?Sized+CommutativeAlgebraRule<R,T>,S:TermMap<T,R>> MulCommutative for ModuleString<R,T,A,S> {}
 
-impl<T:Hash+Eq,R:Distributive,A:?Sized> Distributive for ModuleString<R,T,A> {}
+impl<T,R:Distributive,A:?Sized,S:TermMap<T,R>> Distributive for ModuleString<R,T,A,S> {}
 
-impl<T:Hash+Eq,R:AddAssign,A:?Sized> AddAssign for ModuleString<R,T,A> {
+impl<T,R:AddAssign,A:?Sized,S:TermMap<T,R>> AddAssign for ModuleString<R,T,A,S> {
     fn add_assign(&mut self, rhs:Self) {self.insert(rhs, |r| r)}
 }
-impl<T:Hash+Eq,R:AddAssign+Neg<Output=R>,A:?Sized> SubAssign for ModuleString<R,T,A> {
+impl<T,R:AddAssign+Neg<Output=R>,A:?Sized,S:TermMap<T,R>> SubAssign for ModuleString<R,T,A,S> {
     fn sub_assign(&mut self, rhs:Self) {self.insert(rhs, |r| -r)}
 }
 
-impl<T:Hash+Eq,R:AddAssign,A:?Sized> AddAssign<(R,T)> for ModuleString<R,T,A> {
+impl<T,R:AddAssign,A:?Sized,S:TermMap<T,R>> AddAssign<(R,T)> for ModuleString<R,T,A,S> {
     fn add_assign(&mut self, rhs:(R,T)) {self.insert_term(rhs, |r| r)}
 }
-impl<T:Hash+Eq,R:AddAssign+Neg<Output=R>,A:?Sized> SubAssign<(R,T)> for ModuleString<R,T,A> {
+impl<T,R:AddAssign+Neg<Output=R>,A:?Sized,S:TermMap<T,R>> SubAssign<(R,T)> for ModuleString<R,T,A,S> {
     fn sub_assign(&mut self, rhs:(R,T)) {self.insert_term(rhs, |r| -r)}
 }
 
-impl<T:Hash+Eq,R:AddAssign+One,A:?Sized> AddAssign<T> for ModuleString<R,T,A> {
+impl<T,R:AddAssign+One,A:?Sized,S:TermMap<T,R>> AddAssign<T> for ModuleString<R,T,A,S> {
     fn add_assign(&mut self, rhs:T) {*self+=(R::one(),rhs)}
 }
-impl<T:Hash+Eq,R:AddAssign+Neg<Output=R>+One,A:?Sized> SubAssign<T> for ModuleString<R,T,A> {
+impl<T,R:AddAssign+Neg<Output=R>+One,A:?Sized,S:TermMap<T,R>> SubAssign<T> for ModuleString<R,T,A,S> {
     fn sub_assign(&mut self, rhs:T) {*self-=(R::one(),rhs)}
 }
 
-impl<T:Hash+Eq,R:MulAssign+Clone,A:?Sized> MulAssign<R> for ModuleString<R,T,A> {
+impl<T,R:MulAssign+Clone,A:?Sized,S:TermMap<T,R>> MulAssign<R> for ModuleString<R,T,A,S> {
     fn mul_assign(&mut self, rhs:R) {
         for r2 in self.terms.values_mut() { *r2 *= rhs.clone() }
         self.clean();
     }
 }
-impl<T:Hash+Eq,R:DivAssign+Clone,A:?Sized> DivAssign<R> for ModuleString<R,T,A> {
+impl<T,R:DivAssign+Clone,A:?Sized,S:TermMap<T,R>> DivAssign<R> for ModuleString<R,T,A,S> {
     fn div_assign(&mut self, rhs:R) {
         for r2 in self.terms.values_mut() { *r2 /= rhs.clone() }
         self.clean();
     }
 }
 
-impl_arith!(impl<T,R,A> AddAssign<&Self>.add_assign for ModuleString<R,T,A> where T:Hash+Eq,A:?Sized);
-impl_arith!(impl<T,R,A> SubAssign<&Self>.sub_assign for ModuleString<R,T,A> where T:Hash+Eq,A:?Sized);
+impl_arith!(impl<T,R,A,S> AddAssign<&Self>.add_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
+impl_arith!(impl<T,R,A,S> SubAssign<&Self>.sub_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
 
-impl_arith!(impl<T,R,A> AddAssign<&T>.add_assign for ModuleString<R,T,A> where T:Hash+Eq,A:?Sized);
-impl_arith!(impl<T,R,A> SubAssign<&T>.sub_assign for ModuleString<R,T,A> where T:Hash+Eq,A:?Sized);
+impl_arith!(impl<T,R,A,S> AddAssign<&T>.add_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
+impl_arith!(impl<T,R,A,S> SubAssign<&T>.sub_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
 
-impl_arith!(impl<T,R,A> MulAssign<&R>.mul_assign for ModuleString<R,T,A> where T:Hash+Eq,A:?Sized);
-impl_arith!(impl<T,R,A> DivAssign<&R>.div_assign for ModuleString<R,T,A> where T:Hash+Eq,A:?Sized);
+impl_arith!(impl<T,R,A,S> MulAssign<&R>.mul_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
+impl_arith!(impl<T,R,A,S> DivAssign<&R>.div_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
 
-impl_arith!(impl<T,R,A> Add.add with AddAssign.add_assign for ModuleString<R,T,A> where T:Hash+Eq, A:?Sized);
-impl_arith!(impl<T,R,A> Sub.sub with SubAssign.sub_assign for ModuleString<R,T,A> where T:Hash+Eq, A:?Sized);
-impl_arith!(impl<T,R,A> Mul.mul with MulAssign.mul_assign for ModuleString<R,T,A> where T:Hash+Eq, A:?Sized);
-impl_arith!(impl<T,R,A> Div.div with DivAssign.div_assign for ModuleString<R,T,A> where T:Hash+Eq, A:?Sized);
+impl_arith!(impl<T,R,A,S> Add.add with AddAssign.add_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
+impl_arith!(impl<T,R,A,S> Sub.sub with SubAssign.sub_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
+impl_arith!(impl<T,R,A,S> Mul.mul with MulAssign.mul_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
+impl_arith!(impl<T,R,A,S> Div.div with DivAssign.div_assign for ModuleString<R,T,A,S> where A:?Sized, S:TermMap<T,R>);
 
-impl<T:Hash+Eq,R:Neg,A:?Sized> Neg for ModuleString<R,T,A> {
-    type Output = ModuleString<R::Output,T,A>;
+impl<T,R:Neg,A:?Sized,S:TermMap<T,R>+WithCoeffs<T,R::Output>> Neg for ModuleString<R,T,A,S> {
+    type Output = ModuleString<R::Output,T,A,S::Output>;
     fn neg(self) -> Self::Output {
         ModuleString {
-            terms: self.terms.into_iter().map(|(t,r)| (t,-r)).filter(|(_,r)| r._is_zero()).collect(),
+            terms: self.terms.into_iter().map(|(t,r)| (t,-r)).filter(|(_,r)| !(*r)._is_zero()).collect(),
             rule: PhantomData
         }
     }
 }
 
-impl<'a,T:Hash+Eq,R:Neg,A:?Sized> Neg for &'a ModuleString<R,T,A> where ModuleString<R,T,A>:Clone {
-    type Output = ModuleString<R::Output,T,A>;
+impl<'a,T,R:Neg,A:?Sized,S:TermMap<T,R>+WithCoeffs<T,R::Output>> Neg for &'a ModuleString<R,T,A,S> where ModuleString<R,T,A,S>:Clone {
+    type Output = ModuleString<R::Output,T,A,S::Output>;
     #[inline] fn neg(self) -> Self::Output {-(*self).clone()}
 }
 
-impl<T:Hash+Eq,R:AddAssign,A:?Sized> Zero for ModuleString<R,T,A> {
+impl<T,R:AddAssign,A:?Sized,S:TermMap<T,R>> Zero for ModuleString<R,T,A,S> {
     #[inline] fn zero() -> Self {Default::default()}
     #[inline] fn is_zero(&self) -> bool {self.terms.len()==0}
 }
 
-impl<T:Clone+Hash+Eq,R:PartialEq+UnitalSemiring,A:UnitalAlgebraRule<R,T>+?Sized> One for ModuleString<R,T,A> {
+impl<T:Clone,R:PartialEq+UnitalSemiring,A:UnitalAlgebraRule<R,T>+?Sized,S:TermMap<T,R>+Clone> One for ModuleString<R,T,A,S> {
     #[inline] fn one() -> Self { A::one().into() }
     #[inline] fn is_one(&self) -> bool {
         if self.terms.len()==1 {
             let term = self.iter().next().unwrap();
-            term.0.is_one() && A::is_one(term.1)
+            term.0.is_one() && A::is_one(term.1.borrow())
         } else {
             false
         }
@@ -531,31 +1038,109 @@ impl<T:Clone+Hash+Eq,R:PartialEq+UnitalSemiring,A:UnitalAlgebraRule<R,T>+?Sized>
 }
 
 
-impl<T:Hash+Eq+Clone,R:MulMagma+AddMagma,A:?Sized+AlgebraRule<R,T>> MulAssign<(R,T)> for ModuleString<R,T,A> {
+impl<T:Clone,R:MulMagma+AddMagma,A:?Sized+AlgebraRule<R,T>,S:TermMap<T,R>> ModuleString<R,T,A,S> {
+
+    //multiplies two terms using the rule given by A
+    pub(crate) fn mul_terms((r1,t1):(R,T), (r2,t2):(R,T)) -> (R,T) {
+        let (coeff, t) = A::apply(t1, t2);
+        match coeff {
+            Some(r) => ((r1*r2)*r, t),
+            None => (r1*r2, t),
+        }
+    }
+
+    //adds a term without removing it if the coefficient becomes zero
+    pub(crate) fn accumulate(&mut self, (r,t):(R,T)) {
+        match self.terms.get_mut(&t) {
+            Some(r2) => *r2 += r,
+            None => { self.terms.insert(t, r); },
+        }
+    }
+
+    ///
+    ///Adds the product of `a` and `b` to `self` in place
+    ///
+    ///This is equivalent to `*self += a.clone() * b.clone()`, but neither argument is cloned as a whole
+    ///and every pairwise product of terms is summed directly into `self`'s map instead of into
+    ///temporary ones. Hence, it is the most efficient way to accumulate sums of products, such as
+    ///in dot products or matrix multiplication.
+    ///
+    ///If the `rayon` feature is enabled, large products are split across threads whenever the terms
+    ///and coefficients are [Send] and [Sync].
+    ///
+    ///Furthermore, univariate polynomials (ie, those using the [AddRule] over integer exponents)
+    ///that are large and dense enough are multiplied by copying their coefficients into arrays and
+    ///using Karatsuba's algorithm for [rings](Ring) or a number-theoretic transform for
+    ///primitive signed integers.
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::Zero;
+    ///use free_algebra::{FreeAlgebra, FreeMonoid};
+    ///
+    ///let x:FreeMonoid<_> = 'x'.into();
+    ///let y:FreeMonoid<_> = 'y'.into();
+    ///
+    ///let a = [FreeAlgebra::<i32,_>::from(x.clone()), FreeAlgebra::from(y.clone())];
+    ///let b = [FreeAlgebra::<i32,_>::from(y.clone()) * 2, -FreeAlgebra::<i32,_>::from(x.clone())];
+    ///
+    ///let mut dot = FreeAlgebra::zero();
+    ///for (p, q) in a.iter().zip(b.iter()) {
+    ///    dot.add_mul(p, q);
+    ///}
+    ///
+    ///assert_eq!(dot, FreeAlgebra::from((2, &x*&y)) - (1, &y*&x));
+    ///
+    ///```
+    ///
+    pub fn add_mul(&mut self, a:&Self, b:&Self) {
+        if !<Self as DenseAddMul>::_dense_add_mul(self, a, b) { <Self as AddMul>::_add_mul(self, a, b) }
+    }
+
+    //the single-threaded implementation of add_mul()
+    pub(crate) fn add_mul_serial(&mut self, a:&Self, b:&Self) {
+        for (r1, t1) in a.iter() {
+            for (r2, t2) in b.iter() {
+                let term = Self::mul_terms((r1.clone(),t1.borrow().clone()), (r2.clone(),t2.borrow().clone()));
+                if !term.0._is_zero() { self.accumulate(term); }
+            }
+        }
+        self.clean();
+    }
+
+}
+
+//use specialization to multiply in parallel when possible (see the parallel module)
+pub(crate) trait AddMul { fn _add_mul(&mut self, a:&Self, b:&Self); }
+impl<T:Clone,R:MulMagma+AddMagma,A:?Sized+AlgebraRule<R,T>,S:TermMap<T,R>> AddMul for ModuleString<R,T,A,S> {
+    default fn _add_mul(&mut self, a:&Self, b:&Self) { self.add_mul_serial(a, b) }
+}
+
+impl<T:Clone,R:MulMagma+AddMagma,A:?Sized+AlgebraRule<R,T>,S:TermMap<T,R>> MulAssign<(R,T)> for ModuleString<R,T,A,S> {
     fn mul_assign(&mut self, (r1,t1): (R,T)) {
-        let mut temp = HashMap::with_capacity(0);
+        let mut temp = S::with_capacity(self.len());
         ::std::mem::swap(&mut self.terms, &mut temp);
-        *self = temp.into_iter().map(
-            |(t, r)| {
-                let (coeff, t2) = A::apply(t, t1.clone());
-                match coeff {
-                    Some(r2) => ((r*r1.clone())*r2, t2),
-                    None => (r*r1.clone(), t2),
-                }
-            }
-        ).sum();
+        for (t, r) in temp {
+            let term = Self::mul_terms((r,t), (r1.clone(),t1.clone()));
+            if !term.0._is_zero() { self.accumulate(term); }
+        }
+        self.clean();
     }
 }
 
-impl<T:Hash+Eq+Clone,R:Semiring,A:?Sized+AlgebraRule<R,T>> MulAssign for ModuleString<R,T,A> {
+impl<T:Clone,R:Semiring,A:?Sized+AlgebraRule<R,T>,S:TermMap<T,R>> MulAssign for ModuleString<R,T,A,S> {
     fn mul_assign(&mut self, rhs:Self) {
-        *self = rhs.terms.into_iter().map(|(t, r)| self.clone() * (r,t)).sum();
+        //the product has at least as many terms as either factor in most cases, and for products of
+        //univariate polynomials, exactly one less than the sum
+        let mut product = Self { terms: S::with_capacity(self.len().saturating_add(rhs.len())), rule: PhantomData };
+        product.add_mul(self, &rhs);
+        *self = product;
     }
 }
 
-impl<Z,R,T,A> Pow<Z> for ModuleString<R,T,A> where
+impl<Z,R,T,A,S:TermMap<T,R>+Clone> Pow<Z> for ModuleString<R,T,A,S> where
     Z:Natural,
-    T:Hash+Eq+Clone,
+    T:Clone,
     R:PartialEq+UnitalSemiring,
     A:?Sized+UnitalAlgebraRule<R,T>+AssociativeAlgebraRule<R,T>
 {
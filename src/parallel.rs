@@ -0,0 +1,98 @@
+//!
+//!Parallel implementations of [ModuleString] arithmetic using [rayon]
+//!
+//!With this feature enabled, multiplication (and [add_mul](ModuleString::add_mul())) is split across
+//!threads whenever the product is large enough to be worth it, and [ModuleString]'s implement
+//![FromParallelIterator] and [ParallelExtend] by merging partial sums computed on each thread.
+//!Furthermore, since [ModuleString]'s already implement [Sum], they can also be summed using
+//![ParallelIterator::sum()].
+//!
+//!```
+//!use maths_traits::algebra::Zero;
+//!use free_algebra::FreeModule;
+//!use rayon::prelude::*;
+//!
+//!let p: FreeModule<i64,u32> = (0..1000u32).into_par_iter().map(|i| (i as i64, i % 10)).collect();
+//!let q: FreeModule<i64,u32> = (0..10u32).into_par_iter().map(|i| FreeModule::from(i)).sum();
+//!
+//!assert_eq!(p.len(), 10);
+//!assert_eq!(p.get(&3), (0..100).map(|i| 10*i + 3).sum::<i64>());
+//!assert_eq!(q, (0..10).map(|i| FreeModule::from(i)).sum());
+//!
+//!```
+//!
+//!```
+//!use free_algebra::{FreeAlgebra, FreeMonoid};
+//!
+//!let word = |i:i64, j:i64| FreeMonoid::from(i as u8) * (j as u8);
+//!
+//!//a product with 200*100 pairs of terms is large enough to be multiplied in parallel
+//!let p: FreeAlgebra<i64,u8> = (0..200).map(|i| (i + 1, word(i / 20, i % 20))).collect();
+//!let q: FreeAlgebra<i64,u8> = (0..100).map(|i| (i - 50, word(i % 10, i / 10))).collect();
+//!assert_eq!(p.len() * q.len(), 200 * 99);
+//!
+//!let expected: FreeAlgebra<i64,u8> = q.iter().map(|(r, t)| p.clone() * (*r, t.clone())).sum();
+//!assert_eq!(p * q, expected);
+//!
+//!```
+//!
+
+use super::*;
+use rayon::prelude::*;
+use rayon::iter::{FromParallelIterator, ParallelExtend};
+
+//the number of pairs of terms in a product before it is split across threads
+const PARALLEL_THRESHOLD: usize = 1 << 12;
+
+impl<T,R,A,S> AddMul for ModuleString<R,T,A,S> where
+    T:Clone+Send+Sync,
+    R:MulMagma+AddMagma+Send+Sync,
+    A:?Sized+AlgebraRule<R,T>,
+    S:TermMap<T,R>,
+    Self:Send+Sync
+{
+    fn _add_mul(&mut self, a:&Self, b:&Self) {
+        if a.len().saturating_mul(b.len()) < PARALLEL_THRESHOLD { return self.add_mul_serial(a, b); }
+
+        //multiply the terms of a by b on each thread and merge the partial products at the end
+        let lhs: Vec<_> = a.iter().map(|(r, t)| (r, t.borrow().clone())).collect();
+        let product = lhs.into_par_iter().fold(
+            Self::default,
+            |mut prod, (r1, t1)| {
+                for (r2, t2) in b.iter() {
+                    let term = Self::mul_terms((r1.clone(),t1.clone()), (r2.clone(),t2.borrow().clone()));
+                    if !term.0._is_zero() { prod.accumulate(term); }
+                }
+                prod
+            }
+        ).reduce(Self::default, |mut p1, p2| { p1 += p2; p1 });
+
+        *self += product;
+    }
+}
+
+impl<T:Send,R:AddAssign+Send,A:?Sized,S:TermMap<T,R>> FromParallelIterator<(R,T)> for ModuleString<R,T,A,S> where Self:Send {
+    fn from_par_iter<I:IntoParallelIterator<Item=(R,T)>>(iter:I) -> Self {
+        iter.into_par_iter()
+            .fold(Self::default, |mut m, term| { m += term; m })
+            .reduce(Self::default, |mut m1, m2| { m1 += m2; m1 })
+    }
+}
+
+impl<T:Send,R:AddAssign+One+Send,A:?Sized,S:TermMap<T,R>> FromParallelIterator<T> for ModuleString<R,T,A,S> where Self:Send {
+    fn from_par_iter<I:IntoParallelIterator<Item=T>>(iter:I) -> Self {
+        Self::from_par_iter(iter.into_par_iter().map(|t| (R::one(), t)))
+    }
+}
+
+impl<T:Send,R:AddAssign+Send,A:?Sized,S:TermMap<T,R>> FromParallelIterator<Self> for ModuleString<R,T,A,S> where Self:Send {
+    fn from_par_iter<I:IntoParallelIterator<Item=Self>>(iter:I) -> Self {
+        iter.into_par_iter().reduce(Self::default, |mut m1, m2| { m1 += m2; m1 })
+    }
+}
+
+impl<T:Send,R:AddAssign+Send,A:?Sized,S:TermMap<T,R>> ParallelExtend<(R,T)> for ModuleString<R,T,A,S> where Self:Send {
+    fn par_extend<I:IntoParallelIterator<Item=(R,T)>>(&mut self, iter:I) {
+        *self += Self::from_par_iter(iter);
+    }
+}
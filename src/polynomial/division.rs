@@ -0,0 +1,104 @@
+//!
+//!Contains the multivariate division algorithm
+//!
+
+use super::*;
+
+//the polynomials over a given monomial type
+type Poly<R,T> = ModuleString<R,T,MulRule,BTreeMap<T,R>>;
+
+#[inline] pub(super) fn lm<R,T:Ord>(p: &Poly<R,T>) -> &T { p.leading_term().unwrap().1 }
+
+//subtracts `c*m*g` from `p`, skipping the leading term of `g`
+pub(super) fn sub_shifted<R:Field,T:MonomialTerm>(p: &mut Poly<R,T>, c: &R, m: &T, g: &Poly<R,T>) {
+    for (r, t) in g.iter().rev().skip(1) {
+        *p -= (c.clone() * r.clone(), m.clone() * t.clone());
+    }
+}
+
+//
+//Runs the multivariate division algorithm, returning the remainder
+//
+//Whenever a leading term is divisible by some divisor, `on_reduce` is called with the index of the
+//first such divisor and the term of the quotient
+//
+fn reduce_with<R:Field,T:MonomialTerm,F:FnMut(usize,R,T)>(
+    mut p: Poly<R,T>, divisors: &[Poly<R,T>], mut on_reduce: F
+) -> Poly<R,T> {
+    let mut rem = Poly::zero();
+    while let Some((c, t)) = p.pop_leading_term() {
+        let divisor = divisors.iter().enumerate().filter(|(_,g)| !g.is_zero()).find_map(
+            |(i,g)| t.checked_div(lm(g)).map(|m| (i,g,m))
+        );
+        match divisor {
+            Some((i, g, m)) => {
+                let c = c / g.leading_term().unwrap().0.clone();
+                sub_shifted(&mut p, &c, &m, g);
+                on_reduce(i, c, m);
+            },
+            None => rem += (c, t),
+        }
+    }
+    rem
+}
+
+#[inline]
+pub(super) fn reduce<R:Field,T:MonomialTerm>(p: Poly<R,T>, divisors: &[Poly<R,T>]) -> Poly<R,T> {
+    reduce_with(p, divisors, |_,_,_| ())
+}
+
+impl<R:Field,T:MonomialTerm> ModuleString<R,T,MulRule,BTreeMap<T,R>> {
+
+    ///
+    ///Divides this polynomial by a list of divisors, returning the quotients and remainder
+    ///
+    ///This is the multivariate division algorithm: the greatest remaining term is repeatedly divided
+    ///by the leading term of the first divisor whose leading monomial divides it, or moved to
+    ///the remainder if there is none. Hence, the result satisfies
+    ///`self == q[0]*divisors[0] + ... + q[n]*divisors[n] + r`, where no term of `r` is divisible
+    ///by any of the divisors' leading monomials, and the terms are compared using the monomial order
+    ///of the polynomial type.
+    ///
+    ///Unlike division by a [Gröbner basis](GroebnerBasis), the remainder generally depends on the
+    ///order of the divisors, but it is much cheaper to compute. Any zero divisors are skipped and
+    ///given a zero quotient.
+    ///
+    ///# Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use num_traits::Pow;
+    ///use free_algebra::{Polynomial, Lex};
+    ///
+    ///type P = Polynomial<f64, char, Lex>;
+    ///let x = P::var('x');
+    ///let y = P::var('y');
+    ///
+    ///let f = x.clone().pow(2u32)*y.clone() + x.clone()*y.clone().pow(2u32) + y.clone().pow(2u32);
+    ///let divisors = [x.clone()*y.clone() - P::one(), y.clone().pow(2u32) - P::one()];
+    ///
+    ///let (q, r) = f.div_rem_by(&divisors);
+    ///assert_eq!(q[0].to_string(), "x + y");
+    ///assert_eq!(q[1].to_string(), "1");
+    ///assert_eq!(r.to_string(), "x + y + 1");
+    ///assert_eq!(q[0].clone()*divisors[0].clone() + q[1].clone()*divisors[1].clone() + r, f);
+    ///
+    /////the remainder depends on the order of the divisors
+    ///assert_eq!(f.rem_by(&[divisors[1].clone(), divisors[0].clone()]).to_string(), "2*x + 1");
+    ///
+    ///```
+    ///
+    pub fn div_rem_by(&self, divisors: &[Self]) -> (Vec<Self>, Self) {
+        let mut quotients = vec![Self::zero(); divisors.len()];
+        let rem = reduce_with(self.clone(), divisors, |i, c, m| quotients[i] += (c, m));
+        (quotients, rem)
+    }
+
+    ///
+    ///The remainder of the [multivariate division](ModuleString::div_rem_by) of this polynomial by a list of divisors
+    ///
+    ///This is a normal form of the polynomial modulo the divisors, though it is only unique if they
+    ///form a [Gröbner basis](GroebnerBasis).
+    ///
+    pub fn rem_by(&self, divisors: &[Self]) -> Self { reduce(self.clone(), divisors) }
+
+}
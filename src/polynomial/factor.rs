@@ -0,0 +1,648 @@
+//!
+//!Contains square-free factorization and the factorization of univariate polynomials over
+//![prime fields](Zp) and the integers
+//!
+
+use super::*;
+use super::prime_field::{WithPrime, with_prime, SMALL_PRIMES, LARGE_PRIMES};
+
+//orders factors by degree and then by their coefficients from the highest degree down
+fn sort_factors<R:Ord>(factors: &mut [(UniPoly<R>, usize)]) {
+    factors.sort_by(|(f,i), (g,j)| {
+        f.degree().cmp(&g.degree()).then_with(|| f.coeffs().iter().rev().cmp(g.coeffs().iter().rev())).then(i.cmp(j))
+    });
+}
+
+//uses specialization to take p-th roots over prime fields, where the derivative can vanish
+trait SquareFree: Field {
+    fn _square_free(f: UniPoly<Self>) -> Vec<(UniPoly<Self>, usize)>;
+}
+
+impl<R:Field> SquareFree for R {
+    //Yun's algorithm, which is only correct in characteristic zero
+    default fn _square_free(f: UniPoly<R>) -> Vec<(UniPoly<R>, usize)> {
+        let mut factors = Vec::new();
+
+        let df = f.derivative();
+        let a = f.clone().gcd(df.clone());
+        let (mut b, c) = (f.div_rem(a.clone()).0, df.div_rem(a).0);
+        let mut d = c - b.derivative();
+
+        let mut i = 1;
+        while b.degree() > Some(0) {
+            let a = b.clone().gcd(d.clone());
+            b = b.div_rem(a.clone()).0;
+            let c = d.div_rem(a.clone()).0;
+            if a.degree() > Some(0) { factors.push((a, i)); }
+            d = c - b.derivative();
+            i += 1;
+        }
+
+        factors
+    }
+}
+
+impl<const P: u64> SquareFree for Zp<P> {
+    fn _square_free(f: UniPoly<Self>) -> Vec<(UniPoly<Self>, usize)> {
+        let mut factors = Vec::new();
+
+        //split off the factors whose multiplicities aren't divisible by p
+        let mut c = f.clone().gcd(f.derivative());
+        let mut w = f.div_rem(c.clone()).0;
+        let mut i = 1;
+        while w.degree() > Some(0) {
+            let y = w.clone().gcd(c.clone());
+            let z = w.div_rem(y.clone()).0;
+            if z.degree() > Some(0) { factors.push((z.monic(), i)); }
+            c = c.div_rem(y.clone()).0;
+            w = y;
+            i += 1;
+        }
+
+        //what remains is a p-th power, and since a^p = a for all a in the prime field, its p-th
+        //root is found by dividing all of the exponents by p
+        if c.degree() > Some(0) {
+            let root = UniPoly::new(c.into_coeffs().into_iter().step_by(P as usize).collect());
+            factors.extend(Self::_square_free(root).into_iter().map(|(g, j)| (g, j * P as usize)));
+        }
+
+        factors
+    }
+}
+
+impl<R:Field> UniPoly<R> {
+
+    ///
+    ///Factors this polynomial into powers of square-free polynomials
+    ///
+    ///This returns a list of monic, pairwise coprime, square-free polynomials of positive degree
+    ///along with their multiplicities, in increasing order of multiplicity, such that this
+    ///polynomial is its leading coefficient times the product of each factor raised to its
+    ///multiplicity.
+    ///
+    ///This uses Yun's algorithm, except over [prime fields](Zp), where the `p`th powers are also
+    ///accounted for. Hence, for any other field, the result is only correct in characteristic zero.
+    ///
+    ///## Examples
+    ///```
+    ///use num_traits::Pow;
+    ///use free_algebra::{UniPoly, Zp};
+    ///
+    ///let x = UniPoly::<f64>::x();
+    ///let one = UniPoly::from(vec![1.0]);
+    ///
+    ///let p = (&x - &one).pow(3u32) * (&x + &one) * 2.0;
+    ///assert_eq!(
+    ///    p.square_free_factorization(),
+    ///    vec![(&x + &one, 1), (&x - &one, 3)]
+    ///);
+    ///
+    /////over GF(3), (x+1)^3 = x^3 + 1 has a derivative of zero
+    ///let y = UniPoly::<Zp<3>>::x();
+    ///let q = UniPoly::from(vec![Zp::from(1), Zp::from(0), Zp::from(0), Zp::from(1)]) * &y;
+    ///assert_eq!(
+    ///    q.square_free_factorization(),
+    ///    vec![(y.clone(), 1), (y + UniPoly::from(vec![Zp::from(1)]), 3)]
+    ///);
+    ///
+    ///```
+    ///
+    pub fn square_free_factorization(&self) -> Vec<(Self, usize)> {
+        if self.degree().is_none_or(|n| n==0) { return Vec::new(); }
+        let mut factors = R::_square_free(self.clone());
+        factors.sort_by_key(|(_, i)| *i);
+        factors
+    }
+
+    ///Determines if this polynomial has no repeated factors of positive degree
+    pub fn is_square_free(&self) -> bool {
+        match self.degree() {
+            None => false,
+            Some(0) => true,
+            Some(_) => self.clone().gcd(self.derivative()).degree()==Some(0),
+        }
+    }
+
+}
+
+//a deterministic source of random numbers, eg for the coefficients in the Cantor-Zassenhaus algorithm
+pub(super) struct XorShift(pub(super) u64);
+
+impl XorShift {
+    pub(super) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+//computes b^e mod m using repeated squaring
+fn pow_mod<const P: u64>(b: UniPoly<Zp<P>>, mut e: u64, m: &UniPoly<Zp<P>>) -> UniPoly<Zp<P>> {
+    let (mut b, mut acc) = (b.div_rem(m.clone()).1, UniPoly::one().div_rem(m.clone()).1);
+    while e > 0 {
+        if e & 1 == 1 { acc = (acc * b.clone()).div_rem(m.clone()).1; }
+        b = (b.clone() * b).div_rem(m.clone()).1;
+        e >>= 1;
+    }
+    acc
+}
+
+//splits a monic square-free polynomial into the products of its irreducible factors of each degree
+fn distinct_degree<const P: u64>(mut f: UniPoly<Zp<P>>) -> Vec<(UniPoly<Zp<P>>, usize)> {
+    let mut factors = Vec::new();
+    let x = UniPoly::x();
+
+    //h = x^(p^d) mod f, since the irreducibles of degree dividing d are the factors of x^(p^d) - x
+    let mut h = x.clone();
+    let mut d = 1;
+    while f.degree().unwrap() >= 2*d {
+        h = pow_mod(h, P, &f);
+        let g = f.clone().gcd(h.clone() - x.clone());
+        if g.degree() > Some(0) {
+            f = f.div_rem(g.clone()).0;
+            h = h.div_rem(f.clone()).1;
+            factors.push((g, d));
+        }
+        d += 1;
+    }
+
+    //anything left has no factors of degree at most half its own, so it is irreducible
+    if let Some(n) = f.degree().filter(|n| *n > 0) { factors.push((f, n)); }
+    factors
+}
+
+//splits a monic product of distinct irreducibles of degree d using the Cantor-Zassenhaus algorithm
+fn equal_degree<const P: u64>(f: UniPoly<Zp<P>>, d: usize, rng: &mut XorShift) -> Vec<UniPoly<Zp<P>>> {
+    let n = f.degree().unwrap();
+    if n <= d { return vec![f]; }
+
+    loop {
+        let a = UniPoly::new((0..n).map(|_| Zp::from(rng.next())).collect());
+        if a.degree() < Some(1) { continue; }
+
+        //over odd characteristic, a^((p^d-1)/2) is ±1 modulo each factor, so it splits roughly in
+        //half, and in characteristic 2, the trace a + a^2 + a^4 + ... + a^(2^(d-1)) does the same
+        let b = if P == 2 {
+            let mut t = a.clone();
+            let mut trace = a;
+            for _ in 1..d {
+                t = (t.clone() * t).div_rem(f.clone()).1;
+                trace += t.clone();
+            }
+            trace
+        } else {
+            //(p^d-1)/2 = (p-1)/2 * (1 + p + ... + p^(d-1)), which avoids computing p^d
+            let mut t = pow_mod(a, (P-1)/2, &f);
+            let mut prod = t.clone();
+            for _ in 1..d {
+                t = pow_mod(t, P, &f);
+                prod = (prod * t.clone()).div_rem(f.clone()).1;
+            }
+            prod - UniPoly::one()
+        };
+
+        let g = f.clone().gcd(b);
+        if g.degree() > Some(0) && g.degree() < Some(n) {
+            let h = f.div_rem(g.clone()).0;
+            let mut factors = equal_degree(g, d, rng);
+            factors.extend(equal_degree(h, d, rng));
+            return factors;
+        }
+    }
+}
+
+impl<const P: u64> UniPoly<Zp<P>> {
+
+    ///
+    ///Factors this polynomial into irreducibles over the [prime field](Zp) `GF(P)`
+    ///
+    ///This returns the leading coefficient along with the monic irreducible factors and their
+    ///multiplicities, sorted by degree. The polynomial is first made [square-free](UniPoly::square_free_factorization),
+    ///then each part is split by the degrees of its factors, and finally the factors of equal
+    ///degree are separated using the Cantor–Zassenhaus algorithm. The random choices of
+    ///Cantor–Zassenhaus use a fixed seed, so the result is deterministic.
+    ///
+    ///The zero polynomial has a "leading coefficient" of zero and no factors.
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::{UniPoly, Zp};
+    ///
+    ///type F5 = Zp<5>;
+    ///
+    /////2x^5 + 2x^4 + 2x^3 + 2x^2 + x + 1 = 2(x + 1)^2 (x + 4) (x^2 + 2)
+    ///let coeffs = [1, 1, 2, 2, 2, 2];
+    ///let p = UniPoly::new(coeffs.iter().map(|c| F5::from(*c)).collect());
+    ///
+    ///let poly = |c: &[i32]| UniPoly::new(c.iter().map(|c| F5::from(*c)).collect());
+    ///assert_eq!(
+    ///    p.factor(),
+    ///    (F5::from(2), vec![(poly(&[1, 1]), 2), (poly(&[4, 1]), 1), (poly(&[2, 0, 1]), 1)])
+    ///);
+    ///
+    ///assert!(poly(&[2, 0, 1]).is_irreducible());
+    ///assert!(!poly(&[4, 0, 1]).is_irreducible());
+    ///
+    ///```
+    ///
+    pub fn factor(&self) -> (Zp<P>, Vec<(Self, usize)>) {
+        let lc = match self.leading_coeff() {
+            Some(lc) => *lc,
+            None => return (Zp::zero(), Vec::new()),
+        };
+
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+        let mut factors = Vec::new();
+        for (g, i) in self.square_free_factorization() {
+            for (h, d) in distinct_degree(g) {
+                factors.extend(equal_degree(h, d, &mut rng).into_iter().map(|k| (k, i)));
+            }
+        }
+
+        sort_factors(&mut factors);
+        (lc, factors)
+    }
+
+    ///Determines if this polynomial has positive degree and no non-trivial factors over `GF(P)`
+    pub fn is_irreducible(&self) -> bool {
+        match self.degree() {
+            Some(n) if n > 0 => {
+                self.is_square_free() && distinct_degree(self.clone().monic()).first().is_some_and(|(_, d)| *d==n)
+            },
+            _ => false,
+        }
+    }
+
+}
+
+//
+//Integer polynomials are handled with i128 coefficients
+//
+
+//the GCD of the coefficients with the sign of the leading coefficient
+fn content(f: &UniPoly<i128>) -> i128 {
+    let g = f.coeffs().iter().filter(|c| **c != 0).fold(0, |g, c| if g==0 { c.abs() } else { euclidean(g, c.abs()) });
+    if f.leading_coeff().is_some_and(|c| *c < 0) { -g } else { g }
+}
+
+//divides out the content, making the leading coefficient positive
+fn primitive(f: UniPoly<i128>) -> UniPoly<i128> {
+    match content(&f) {
+        0 => f,
+        c => UniPoly::new(f.into_coeffs().into_iter().map(|a| a / c).collect()),
+    }
+}
+
+//divides two polynomials if the quotient has integer coefficients
+fn exact_div(f: &UniPoly<i128>, g: &UniPoly<i128>) -> Option<UniPoly<i128>> {
+    let m = g.degree()?;
+    let n = match f.degree() {
+        Some(n) if n >= m => n,
+        Some(_) => return None,
+        None => return Some(UniPoly::zero()),
+    };
+
+    let mut r = f.coeffs().to_vec();
+    let mut q = vec![0; n - m + 1];
+    for i in (0..q.len()).rev() {
+        let lc = g.coeffs()[m];
+        if r[i+m] % lc != 0 { return None; }
+        let c = r[i+m] / lc;
+        for (r_j, g_j) in r[i..=i+m].iter_mut().zip(g.coeffs()) {
+            *r_j = r_j.checked_sub(c.checked_mul(*g_j)?)?;
+        }
+        q[i] = c;
+    }
+
+    if r.iter().all(|c| *c==0) { Some(UniPoly::new(q)) } else { None }
+}
+
+//
+//Arithmetic modulo m for the Hensel lifting
+//
+
+fn reduce(f: &UniPoly<i128>, m: i128) -> UniPoly<i128> {
+    UniPoly::new(f.coeffs().iter().map(|c| c.rem_euclid(m)).collect())
+}
+
+fn add_mod(f: &UniPoly<i128>, g: &UniPoly<i128>, m: i128) -> UniPoly<i128> { reduce(&(f + g), m) }
+fn sub_mod(f: &UniPoly<i128>, g: &UniPoly<i128>, m: i128) -> UniPoly<i128> { reduce(&(f - g), m) }
+
+fn mul_mod(f: &UniPoly<i128>, g: &UniPoly<i128>, m: i128) -> UniPoly<i128> {
+    if f.is_zero() || g.is_zero() { return UniPoly::zero(); }
+    let mut prod = vec![0; f.coeffs().len() + g.coeffs().len() - 1];
+    for (i, a) in f.coeffs().iter().enumerate() {
+        for (j, b) in g.coeffs().iter().enumerate() {
+            prod[i+j] = (prod[i+j] + a * b % m) % m;
+        }
+    }
+    UniPoly::new(prod)
+}
+
+//division by a monic polynomial modulo m
+fn div_rem_mod(f: &UniPoly<i128>, g: &UniPoly<i128>, m: i128) -> (UniPoly<i128>, UniPoly<i128>) {
+    let deg_g = g.degree().unwrap();
+    let deg_f = match f.degree() {
+        Some(n) if n >= deg_g => n,
+        _ => return (UniPoly::zero(), f.clone()),
+    };
+
+    let mut r = f.coeffs().to_vec();
+    let mut q = vec![0; deg_f - deg_g + 1];
+    for i in (0..q.len()).rev() {
+        let c = r[i + deg_g];
+        for (r_j, g_j) in r[i..=i+deg_g].iter_mut().zip(g.coeffs()) {
+            *r_j = (*r_j - c * g_j % m).rem_euclid(m);
+        }
+        q[i] = c;
+    }
+    r.truncate(deg_g);
+    (UniPoly::new(q), UniPoly::new(r))
+}
+
+//the inverse of a unit modulo m
+pub(super) fn inv_mod(a: i128, m: i128) -> i128 {
+    let (mut r0, mut r1, mut s0, mut s1) = (a.rem_euclid(m), m, 1i128, 0i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q*r1);
+        (s0, s1) = (s1, s0 - q*s1);
+    }
+    s0.rem_euclid(m)
+}
+
+//the representative of each coefficient with the least absolute value
+fn symmetric(f: &UniPoly<i128>, m: i128) -> UniPoly<i128> {
+    UniPoly::new(f.coeffs().iter().map(|c| if *c > m/2 { c - m } else { *c }).collect())
+}
+
+fn from_zp<const P: u64>(f: &UniPoly<Zp<P>>) -> UniPoly<i128> {
+    UniPoly::new(f.coeffs().iter().map(|c| c.value() as i128).collect())
+}
+
+fn to_zp<const P: u64>(f: &UniPoly<i128>) -> UniPoly<Zp<P>> {
+    UniPoly::new(f.coeffs().iter().map(|c| Zp::from(*c)).collect())
+}
+
+//the Mignotte bound on the coefficients of any factor of f times lc
+fn mignotte_bound(f: &UniPoly<i128>, lc: i128) -> f64 {
+    let n = f.degree().unwrap();
+    let norm = f.coeffs().iter().map(|c| c.abs()).max().unwrap() as f64;
+    ((n + 1) as f64).sqrt() * 2f64.powi(n as i32) * norm * lc.abs() as f64
+}
+
+//
+//The square-free factorization of a primitive polynomial with a positive leading coefficient
+//
+//Since the coefficients in a pseudo-remainder sequence quickly overflow, this is instead computed
+//modulo a few large primes, and the result is checked by trial division in case a prime happens
+//to divide a discriminant or the factors have coefficients too large to be found this way
+//
+fn square_free(f: &UniPoly<i128>) -> Option<Vec<(UniPoly<i128>, usize)>> {
+    struct SquareFreeMod<'a>(&'a UniPoly<i128>);
+    impl WithPrime for SquareFreeMod<'_> {
+        type Output = Option<Vec<(UniPoly<i128>, usize)>>;
+        fn run<const P: u64>(self) -> Self::Output {
+            let f = self.0;
+            let g = to_zp::<P>(f);
+            if g.degree() != f.degree() { return None; }
+
+            let lc = Zp::from(*f.leading_coeff().unwrap());
+            let factors = g.square_free_factorization().into_iter().map(
+                |(h, i)| (primitive(symmetric(&from_zp(&(h * lc)), P as i128)), i)
+            ).collect::<Vec<_>>();
+
+            let mut q = f.clone();
+            for (h, i) in factors.iter() {
+                for _ in 0..*i { q = exact_div(&q, h)?; }
+            }
+            if q.is_one() { Some(factors) } else { None }
+        }
+    }
+
+    LARGE_PRIMES.iter().find_map(|p| with_prime(*p, SquareFreeMod(f)))
+}
+
+//
+//Lifts a factorization f = g*h mod p with h monic to one modulo m using quadratic Hensel steps,
+//where s*g + t*h = 1 mod p
+//
+fn hensel_lift<const P: u64>(
+    f: &UniPoly<i128>, g: &UniPoly<Zp<P>>, h: &UniPoly<Zp<P>>, s: &UniPoly<Zp<P>>, t: &UniPoly<Zp<P>>, m: i128
+) -> (UniPoly<i128>, UniPoly<i128>) {
+    let (mut g, mut h, mut s, mut t) = (from_zp(g), from_zp(h), from_zp(s), from_zp(t));
+    let mut q = P as i128;
+    while q < m {
+        q = (q * q).min(m);
+
+        //lift the factors
+        let e = sub_mod(f, &mul_mod(&g, &h, q), q);
+        let (a, b) = div_rem_mod(&mul_mod(&s, &e, q), &h, q);
+        g = add_mod(&g, &add_mod(&mul_mod(&t, &e, q), &mul_mod(&a, &g, q), q), q);
+        h = add_mod(&h, &b, q);
+
+        //lift the Bezout coefficients
+        let e = sub_mod(&add_mod(&mul_mod(&s, &g, q), &mul_mod(&t, &h, q), q), &UniPoly::one(), q);
+        let (c, d) = div_rem_mod(&mul_mod(&s, &e, q), &h, q);
+        s = sub_mod(&s, &d, q);
+        t = sub_mod(&t, &add_mod(&mul_mod(&t, &e, q), &mul_mod(&c, &g, q), q), q);
+    }
+    (g, h)
+}
+
+//lifts the monic factors of f mod p to monic factors mod m
+fn multifactor_lift<const P: u64>(f: &UniPoly<i128>, factors: &[UniPoly<Zp<P>>], m: i128) -> Vec<UniPoly<i128>> {
+    if factors.len() == 1 {
+        let lc_inv = inv_mod(*f.leading_coeff().unwrap(), m);
+        return vec![reduce(&UniPoly::new(f.coeffs().iter().map(|c| c.rem_euclid(m) * lc_inv % m).collect()), m)];
+    }
+
+    let (left, right) = factors.split_at(factors.len() / 2);
+    let lc = Zp::from(*f.leading_coeff().unwrap());
+    let g = left.iter().fold(UniPoly::constant(lc), |g, k| g * k.clone());
+    let h = right.iter().fold(UniPoly::one(), |h, k| h * k.clone());
+    let (_, s, t) = g.clone().extended_gcd(h.clone());
+
+    let (g, h) = hensel_lift(f, &g, &h, &s, &t, m);
+    let mut lifted = multifactor_lift(&g, left, m);
+    lifted.extend(multifactor_lift(&h, right, m));
+    lifted
+}
+
+//steps to the next subset of the given size in lexicographic order
+fn next_subset(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    for i in (0..k).rev() {
+        if indices[i] < n - k + i {
+            indices[i] += 1;
+            for j in i+1..k { indices[j] = indices[j-1] + 1; }
+            return true;
+        }
+    }
+    false
+}
+
+//
+//Factors a square-free primitive polynomial with a positive leading coefficient using the prime p
+//
+//Returns `None` if the factors can't be shown to be irreducible using 128-bit arithmetic
+//
+fn factor_square_free_mod<const P: u64>(f: &UniPoly<i128>) -> Option<Vec<UniPoly<i128>>> {
+    let modular = to_zp::<P>(f).factor().1.into_iter().map(|(g, _)| g).collect::<Vec<_>>();
+    if modular.len() <= 1 { return Some(vec![f.clone()]); }
+
+    //lift to a modulus bigger than twice the coefficients of any factor times the leading coefficient
+    //if the products of the residues still fit, and otherwise stop early and check each factor found
+    let lc = *f.leading_coeff().unwrap();
+    let bound = mignotte_bound(f, lc);
+    let mut m = P as i128;
+    while (m as f64) <= 2.0 * bound && m * (P as i128) < 1 << 62 { m *= P as i128; }
+    let irreducible = |g: &UniPoly<i128>| (m as f64) > 2.0 * bound || (m as f64) > 2.0 * mignotte_bound(g, lc);
+    let mut lifted = multifactor_lift(f, &modular, m);
+
+    //recombine the lifted factors by trying products of successively larger subsets
+    let mut f = f.clone();
+    let mut factors = Vec::new();
+    let mut k = 1;
+    'outer: while 2*k <= lifted.len() {
+        let mut subset = (0..k).collect::<Vec<_>>();
+        loop {
+            let lc = UniPoly::constant(f.leading_coeff().unwrap().rem_euclid(m));
+            let g = subset.iter().fold(lc, |g, i| mul_mod(&g, &lifted[*i], m));
+            let g = primitive(symmetric(&g, m));
+            if let Some(q) = exact_div(&f, &g) {
+                //a smaller subset would have given any proper factor of g if m is large enough
+                if !irreducible(&g) { return None; }
+                factors.push(g);
+                f = q;
+                for i in subset.into_iter().rev() { lifted.remove(i); }
+                continue 'outer;
+            }
+            if !next_subset(&mut subset, lifted.len()) { break; }
+        }
+        k += 1;
+    }
+
+    if !irreducible(&f) { return None; }
+    factors.push(f);
+    Some(factors)
+}
+
+//the number of irreducible factors modulo P if P is a valid choice
+struct CountFactors<'a>(&'a UniPoly<i128>);
+impl WithPrime for CountFactors<'_> {
+    type Output = Option<usize>;
+    fn run<const P: u64>(self) -> Option<usize> {
+        let g = to_zp::<P>(self.0);
+        if g.degree() != self.0.degree() || !g.is_square_free() { return None; }
+        Some(distinct_degree(g.monic()).iter().map(|(h, d)| h.degree().unwrap() / d).sum())
+    }
+}
+
+struct FactorSquareFreeMod<'a>(&'a UniPoly<i128>);
+impl WithPrime for FactorSquareFreeMod<'_> {
+    type Output = Option<Vec<UniPoly<i128>>>;
+    fn run<const P: u64>(self) -> Self::Output { factor_square_free_mod::<P>(self.0) }
+}
+
+//picks a prime modulo which a square-free polynomial stays square-free and has the fewest factors
+fn factor_square_free(f: &UniPoly<i128>) -> Option<Vec<UniPoly<i128>>> {
+    if f.degree() <= Some(1) { return Some(vec![f.clone()]); }
+
+    //try a few valid primes to find one giving few modular factors to recombine
+    let mut candidates = SMALL_PRIMES.iter().filter_map(|p| with_prime(*p, CountFactors(f)).map(|c| (c, *p)));
+    let best = candidates.by_ref().take(4).min()?;
+    with_prime(best.1, FactorSquareFreeMod(f))
+}
+
+impl<Z:Integer> UniPoly<Z> {
+
+    ///
+    ///Factors this polynomial into irreducibles over the integers
+    ///
+    ///This returns the content (with the sign of the leading coefficient) along with the primitive
+    ///irreducible factors, with positive leading coefficients, and their multiplicities, sorted by
+    ///degree.
+    ///
+    ///After taking the square-free factorization, each part is factored modulo a small prime with
+    ///[Cantor–Zassenhaus](UniPoly::factor), the modular factors are lifted with quadratic Hensel
+    ///lifting to a modulus large enough to bound the coefficients of the true factors, and then
+    ///the lifted factors are recombined into the factors over the integers.
+    ///
+    ///The zero polynomial has a content of zero and no factors.
+    ///
+    ///The lifting is done with 128-bit arithmetic, so it stops at a modulus below `2^62`, and each
+    ///factor is then only accepted if the Mignotte bound on its own factors shows that it is
+    ///irreducible. In practice, this limits the irreducible factors to a degree of about 55, or
+    ///less if their coefficients are large, and [try_factor](UniPoly::try_factor) can be used to
+    ///handle larger ones without panicking.
+    ///
+    ///## Panics
+    ///If the coefficients don't fit in an [i128] or if the factors can't be certified as irreducible
+    ///with 128-bit arithmetic.
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let poly = |c: &[i64]| UniPoly::from(c.to_vec());
+    ///
+    /////-6 (x^2 - x + 1) (x^2 + 1)^2 (2x^3 + 3)
+    ///let p = poly(&[-1, 0, -2, 0, -1]) * poly(&[1, -1, 1]) * poly(&[3, 0, 0, 2]) * 6;
+    ///assert_eq!(
+    ///    p.factor(),
+    ///    (-6, vec![(poly(&[1, -1, 1]), 1), (poly(&[1, 0, 1]), 2), (poly(&[3, 0, 0, 2]), 1)])
+    ///);
+    ///
+    /////x^4 + 1 is irreducible over the integers but factors modulo every prime
+    ///assert_eq!(poly(&[1, 0, 0, 0, 1]).factor(), (1, vec![(poly(&[1, 0, 0, 0, 1]), 1)]));
+    ///
+    ///```
+    ///
+    pub fn factor(&self) -> (Z, Vec<(Self, usize)>) {
+        self.try_factor().expect("the polynomial is too large to factor with 128-bit arithmetic")
+    }
+
+    ///
+    ///[Factors](UniPoly::factor) this polynomial over the integers, returning `None` instead of
+    ///panicking if the coefficients don't fit in an [i128] or the factors can't be certified as
+    ///irreducible with 128-bit arithmetic
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    /////x^60 - 1 is the product of the cyclotomic polynomials of the divisors of 60
+    ///let mut c = vec![0i64; 61];
+    ///c[0] = -1;
+    ///c[60] = 1;
+    ///let (_, factors) = UniPoly::from(c).try_factor().unwrap();
+    ///assert_eq!(factors.len(), 12);
+    ///assert_eq!(factors.last().unwrap().0.degree(), Some(16));
+    ///
+    /////but an irreducible polynomial of degree 80 is too large to certify
+    ///let mut c = vec![0i64; 81];
+    ///c[0] = -2;
+    ///c[80] = 1;
+    ///assert_eq!(UniPoly::from(c).try_factor(), None);
+    ///
+    ///```
+    ///
+    pub fn try_factor(&self) -> Option<(Z, Vec<(Self, usize)>)> {
+        let f = UniPoly::new(self.coeffs().iter().map(|c| c.to_i128()).collect::<Option<Vec<_>>>()?);
+        let c = content(&f);
+        if c==0 { return Some((Z::zero(), Vec::new())); }
+
+        let mut factors = Vec::new();
+        for (g, i) in square_free(&primitive(f))? {
+            factors.extend(factor_square_free(&g)?.into_iter().map(|h| (h, i)));
+        }
+        sort_factors(&mut factors);
+
+        let convert = |c: &i128| Z::from_i128(*c);
+        let factors = factors.into_iter().map(|(h, i)| Some((UniPoly::new(h.coeffs().iter().map(convert).collect::<Option<_>>()?), i)));
+        Some((convert(&c)?, factors.collect::<Option<_>>()?))
+    }
+
+}
@@ -0,0 +1,371 @@
+//!
+//!Contains exact division and GCDs of multivariate polynomials
+//!
+
+use super::*;
+
+//the polynomials over a given monomial type
+type Poly<R,T> = ModuleString<R,T,MulRule,BTreeMap<T,R>>;
+
+//uses specialization to take the GCD of coefficients when possible
+pub(super) trait CoeffGcd: Sized {
+    //a GCD of two coefficients
+    fn _coeff_gcd(self, rhs: Self) -> Self;
+
+    //the unit to divide by to make this coefficient the leading coefficient of a primitive polynomial
+    fn _unit_part(&self) -> Self;
+}
+
+impl<R:IntegralDomain> CoeffGcd for R {
+    //without a GCD, every nonzero coefficient is assumed to be a unit, as in a field
+    default fn _coeff_gcd(self, rhs: Self) -> Self {
+        if self.is_zero() && rhs.is_zero() { R::zero() } else { R::one() }
+    }
+    default fn _unit_part(&self) -> Self { self.clone() }
+}
+
+impl<R:GCDDomain> CoeffGcd for R {
+    default fn _coeff_gcd(self, rhs: Self) -> Self {
+        //some implementations give a GCD of zero if either argument is zero
+        let g = if self.is_zero() { rhs } else if rhs.is_zero() { self } else { self.gcd(rhs) };
+        g._abs_if_negative().unwrap_or(g)
+    }
+    default fn _unit_part(&self) -> Self {
+        if self._abs_if_negative().is_some() { -R::one() } else { R::one() }
+    }
+}
+
+//
+//Divides coefficients exactly
+//
+//This uses the division algorithm when available since the primitive integers implement
+//Divisibility::divide() with the arguments in the opposite order from every other type
+//
+pub(super) trait ExactDiv: Sized {
+    fn _exact_div(self, rhs: Self) -> Option<Self>;
+}
+
+impl<R:IntegralDomain> ExactDiv for R {
+    default fn _exact_div(self, rhs: Self) -> Option<Self> { rhs.divide(self) }
+}
+
+impl<R:IntegralDomain+EuclideanDiv> ExactDiv for R {
+    default fn _exact_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() { return None; }
+        let (q, r) = self.div_alg(rhs);
+        if r.is_zero() { Some(q) } else { None }
+    }
+}
+
+impl<R:AddAssign+Zero, T:MonomialTerm> ModuleString<R,T,MulRule,BTreeMap<T,R>> {
+
+    ///
+    ///Writes this polynomial as a univariate polynomial in `v` with coefficients in the other variables
+    ///
+    ///# Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use num_traits::Pow;
+    ///use free_algebra::{Polynomial, UniPoly};
+    ///
+    ///let x = Polynomial::<i32>::var("x");
+    ///let y = Polynomial::<i32>::var("y");
+    ///
+    ///let p = x.clone().pow(2u32)*y.clone() + x.clone()*3 + y.clone();
+    ///let q = p.to_univariate(&"x".to_string());
+    ///
+    ///assert_eq!(q, UniPoly::from(vec![y, Polynomial::constant(3), Polynomial::var("y")]));
+    ///assert_eq!(Polynomial::from_univariate(q, &"x".to_string()), p);
+    ///
+    ///```
+    ///
+    pub fn to_univariate(&self, v: &T::Var) -> UniPoly<Self> where R:Clone {
+        let deg = self.iter().map(|(_,m)| m.exponent_of(v)).max().unwrap_or(0) as usize;
+        let mut coeffs: Vec<Self> = (0..=deg).map(|_| Self::zero()).collect();
+        for (r, m) in self.iter() {
+            let e = m.exponent_of(v);
+            coeffs[e as usize] += (r.clone(), m.checked_div(&T::var_pow(v, e)).unwrap());
+        }
+        UniPoly::new(coeffs)
+    }
+
+    ///
+    ///Converts a univariate polynomial in `v` back into a multivariate one
+    ///
+    ///This is the inverse of [to_univariate](ModuleString::to_univariate) so long as none of the
+    ///coefficients contain `v`
+    ///
+    pub fn from_univariate(p: UniPoly<Self>, v: &T::Var) -> Self {
+        p.into_coeffs().into_iter().enumerate().flat_map(|(i, c)| {
+            let x = T::var_pow(v, i as u32);
+            c.into_iter().map(move |(r, m)| (r, m * x.clone()))
+        }).collect()
+    }
+
+}
+
+//the GCD of the coefficients of a univariate polynomial
+fn univariate_content<R:IntegralDomain+PartialEq,T:MonomialTerm>(p: &UniPoly<Poly<R,T>>) -> Poly<R,T> {
+    p.coeffs().iter().fold(Poly::zero(), |g, c| poly_gcd(g, c.clone()))
+}
+
+//divides every coefficient of a univariate polynomial by their GCD
+fn univariate_primitive<R:IntegralDomain+PartialEq,T:MonomialTerm>(p: UniPoly<Poly<R,T>>) -> UniPoly<Poly<R,T>> {
+    if p.is_zero() { return p; }
+    let c = univariate_content(&p);
+    UniPoly::new(p.into_coeffs().into_iter().map(|a| a.exact_div(&c).unwrap()).collect())
+}
+
+//makes the leading coefficient unit normal, ie, 1 over fields and positive over the integers
+fn normalize<R:IntegralDomain+PartialEq,T:MonomialTerm>(p: Poly<R,T>) -> Poly<R,T> {
+    match p.leading_term() {
+        Some((lc, _)) => {
+            let u = lc._unit_part();
+            if u._is_one() { p } else { p.into_iter().map(|(r,m)| (r._exact_div(u.clone()).unwrap(), m)).collect() }
+        },
+        None => p,
+    }
+}
+
+//raises a polynomial to a small power
+fn power<R:IntegralDomain+PartialEq,T:MonomialTerm>(p: &Poly<R,T>, n: usize) -> Poly<R,T> {
+    (0..n).fold(Poly::one(), |acc, _| acc * p.clone())
+}
+
+//uses specialization to pick the modular algorithm over the integers
+trait PolyGcd: Sized {
+    fn _try_poly_gcd<T:MonomialTerm>(a: Poly<Self,T>, b: Poly<Self,T>) -> Option<Poly<Self,T>>;
+}
+
+impl<R:IntegralDomain+PartialEq> PolyGcd for R {
+    default fn _try_poly_gcd<T:MonomialTerm>(a: Poly<R,T>, b: Poly<R,T>) -> Option<Poly<R,T>> {
+        Some(subresultant_gcd(a, b))
+    }
+}
+
+impl<Z:Integer> PolyGcd for Z {
+    fn _try_poly_gcd<T:MonomialTerm>(a: Poly<Z,T>, b: Poly<Z,T>) -> Option<Poly<Z,T>> {
+        //with a zero, the GCD is just the other polynomial, so nothing can overflow
+        if a.is_zero() || b.is_zero() { return Some(subresultant_gcd(a, b)); }
+        modular::modular_gcd(&a, &b).map(normalize)
+    }
+}
+
+fn poly_gcd<R:IntegralDomain+PartialEq,T:MonomialTerm>(a: Poly<R,T>, b: Poly<R,T>) -> Poly<R,T> {
+    R::_try_poly_gcd(a, b).expect("the GCD of the polynomials is too large to compute with 128-bit arithmetic")
+}
+
+//
+//Computes GCDs recursively by writing both polynomials in terms of their first variable and
+//using a subresultant pseudo-remainder sequence on the result, where the contents are found by
+//recursing on the remaining variables
+//
+fn subresultant_gcd<R:IntegralDomain+PartialEq,T:MonomialTerm>(a: Poly<R,T>, b: Poly<R,T>) -> Poly<R,T> {
+    if a.is_zero() { return normalize(b); }
+    if b.is_zero() { return normalize(a); }
+
+    let var = a.iter().chain(b.iter()).filter_map(|(_,m)| m.variables().into_iter().next()).min();
+    let v = match var {
+        Some(v) => v,
+        None => {
+            //both are constants
+            let (ca, cb) = (a.leading_term().unwrap().0.clone(), b.leading_term().unwrap().0.clone());
+            return normalize(Poly::from((ca._coeff_gcd(cb), T::one())));
+        }
+    };
+
+    let (ua, ub) = (a.to_univariate(&v), b.to_univariate(&v));
+    let content = poly_gcd(univariate_content(&ua), univariate_content(&ub));
+
+    let (mut p, mut q) = (univariate_primitive(ua), univariate_primitive(ub));
+    if p.degree() < q.degree() { ::std::mem::swap(&mut p, &mut q); }
+
+    //the subresultant PRS, which divides out a known factor of each pseudo-remainder to keep the
+    //coefficients from growing exponentially
+    let (mut g, mut h) = (Poly::one(), Poly::one());
+    while !q.is_zero() {
+        let delta = p.degree().unwrap() - q.degree().unwrap();
+        let r = p.pseudo_div_rem(q.clone()).1;
+        let d = g * power(&h, delta);
+        p = q;
+        q = UniPoly::new(r.into_coeffs().into_iter().map(|c| c.exact_div(&d).unwrap()).collect());
+
+        g = p.coeffs().last().unwrap().clone();
+        h = if delta==0 { h } else { power(&g, delta).exact_div(&power(&h, delta-1)).unwrap() };
+    }
+
+    normalize(content * Poly::from_univariate(univariate_primitive(p), &v))
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> ModuleString<R,T,MulRule,BTreeMap<T,R>> {
+
+    ///
+    ///Divides this polynomial by another if the result is also a polynomial
+    ///
+    ///# Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::Polynomial;
+    ///
+    ///let x = Polynomial::<i32>::var("x");
+    ///let y = Polynomial::<i32>::var("y");
+    ///
+    ///let p = x.clone()*x.clone() - y.clone()*y.clone()*4;
+    ///let q = x.clone() + y.clone()*2;
+    ///
+    ///assert_eq!(p.exact_div(&q), Some(x.clone() - y.clone()*2));
+    ///assert_eq!(p.exact_div(&(x.clone() + y.clone())), None);
+    ///assert_eq!((p.clone()*3).exact_div(&Polynomial::constant(3)), Some(p));
+    ///
+    ///```
+    ///
+    pub fn exact_div(&self, rhs: &Self) -> Option<Self> {
+        let (lc, lm) = rhs.leading_term()?;
+        let mut p = self.clone();
+        let mut q = Self::zero();
+        while let Some((c, t)) = p.pop_leading_term() {
+            let (c, m) = (c._exact_div(lc.clone())?, t.checked_div(lm)?);
+            for (r, s) in rhs.iter().rev().skip(1) {
+                p -= (c.clone() * r.clone(), m.clone() * s.clone());
+            }
+            q += (c, m);
+        }
+        Some(q)
+    }
+
+    ///
+    ///The GCD of the coefficients, normalized so that the primitive part has a leading coefficient
+    ///of one over fields and a positive one over the integers
+    ///
+    ///Over coefficients that aren't a [GCD domain](GCDDomain), every nonzero coefficient is assumed
+    ///to be a unit, so the content is just the leading coefficient.
+    ///
+    ///# Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::Polynomial;
+    ///
+    ///let x = Polynomial::<i32>::var("x");
+    ///let y = Polynomial::<i32>::var("y");
+    ///let p = y.clone() * 4 - x.clone()*x.clone()*y.clone()*6;
+    ///
+    ///assert_eq!(p.content(), -2);
+    ///assert_eq!(p.primitive_part(), x.clone()*x.clone()*y.clone()*3 - y.clone()*2);
+    ///
+    ///let q = Polynomial::<f64>::var("x")*2.0 + Polynomial::one();
+    ///assert_eq!(q.content(), 2.0);
+    ///assert_eq!(q.primitive_part().to_string(), "x + 0.5");
+    ///
+    ///```
+    ///
+    pub fn content(&self) -> R {
+        match self.leading_term() {
+            Some((lc, _)) => self.iter().fold(R::zero(), |g, (r,_)| g._coeff_gcd(r.clone())) * lc._unit_part(),
+            None => R::zero(),
+        }
+    }
+
+    ///
+    ///Computes the [GCD](GCD::gcd) of two polynomials, returning `None` instead of panicking if it
+    ///can't be found with fixed-width integer coefficients
+    ///
+    ///This only happens over the primitive integers, when the intermediate values in the
+    ///modular algorithm don't fit in an [i128] or the GCD itself doesn't fit in the coefficient type.
+    ///
+    ///# Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::Polynomial;
+    ///
+    ///let x = Polynomial::<i64>::var("x");
+    ///let c = 3i64 << 60;
+    ///
+    /////the GCD is x + c, with a coefficient bigger than 2^61
+    ///let f = x.clone()*x.clone() + x.clone()*(c + 1) + Polynomial::constant(c);
+    ///let g = x.clone()*x.clone() + x.clone()*(c - 1) - Polynomial::constant(c);
+    ///
+    ///assert_eq!(f.try_gcd(&g), Some(x.clone() + Polynomial::constant(c)));
+    ///assert_eq!(f.gcd(g), x + Polynomial::constant(c));
+    ///
+    ///```
+    ///
+    pub fn try_gcd(&self, rhs: &Self) -> Option<Self> { R::_try_poly_gcd(self.clone(), rhs.clone()) }
+
+    ///This polynomial divided by its [content](ModuleString::content)
+    pub fn primitive_part(&self) -> Self {
+        if self.is_zero() { return self.clone(); }
+        let c = self.content();
+        self.iter().map(|(r,m)| (r.clone()._exact_div(c.clone()).unwrap(), m.clone())).collect()
+    }
+
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> Divisibility for ModuleString<R,T,MulRule,BTreeMap<T,R>> {
+    fn divides(self, rhs: Self) -> bool {
+        if self.is_zero() { rhs.is_zero() } else { rhs.exact_div(&self).is_some() }
+    }
+    fn divide(self, rhs: Self) -> Option<Self> { rhs.exact_div(&self) }
+    fn unit(&self) -> bool {
+        self.len()==1 && self.leading_term().is_some_and(|(r,m)| m.is_one() && r.unit())
+    }
+    fn inverse(self) -> Option<Self> {
+        if self.unit() { self.leading_term().unwrap().0.clone().inverse().map(|r| (r, T::one()).into()) } else { None }
+    }
+}
+
+///
+///Computes GCDs by recursively writing the polynomials as univariate polynomials in their first
+///variable and using a subresultant pseudo-remainder sequence, so no fractions are ever introduced.
+///
+///Over the [integers](Integer), the coefficients in such a sequence grow far too quickly for a
+///fixed-width type, so Brown's modular algorithm is used instead: the GCD is found modulo several
+///large primes by evaluating all but one variable and interpolating the results, and these images
+///are then combined with the Chinese remainder theorem and checked by trial division.
+///
+///## Panics
+///Over the primitive integers, if 128-bit intermediates aren't enough for this or the GCD doesn't
+///fit in the coefficient type. Use [try_gcd](ModuleString::try_gcd) to handle this case.
+///
+///This works over any field or [GCD domain](GCDDomain), like the integers, and the result is
+///normalized to have a leading coefficient of one over fields and a positive one over the integers.
+///
+///## Examples
+///```
+///use maths_traits::algebra::*;
+///use num_traits::Pow;
+///use free_algebra::Polynomial;
+///
+///let x = Polynomial::<i64>::var("x");
+///let y = Polynomial::<i64>::var("y");
+///
+///let a = (x.clone() + y.clone()).pow(2u32) * (x.clone() - y.clone()*2) * 6;
+///let b = (x.clone() + y.clone()) * (x.clone()*x.clone() + y.clone()) * 4;
+///
+///assert_eq!(a.clone().gcd(b.clone()), (x.clone() + y.clone()) * 2);
+///assert_eq!(a.clone().lcm(b.clone()).exact_div(&a), Some((x.clone()*x.clone() + y.clone()) * 2));
+///
+/////a case where the subresultant sequence would overflow
+///let z = Polynomial::<i64>::var("z");
+///let g = (x.clone()*x.clone()*y.clone() + y.clone()*z.clone()*3 - x.clone()*5 + Polynomial::constant(7)).pow(4u32);
+///let u = x.clone()*y.clone() - z.clone()*2 + Polynomial::one();
+///let v = y.clone()*y.clone() + x.clone()*z.clone()*4 - Polynomial::constant(3);
+///assert_eq!((g.clone()*u*6).gcd(g.clone()*v*4), g*2);
+///
+/////over a field, the GCD is monic
+///let p = Polynomial::<f64>::var("x") * 3.0 - Polynomial::<f64>::var("y");
+///let q = p.clone() * Polynomial::<f64>::var("y") * 2.0;
+///assert_eq!(p.gcd(q).to_string(), "x - 0.3333333333333333*y");
+///
+///```
+///
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> GCD for ModuleString<R,T,MulRule,BTreeMap<T,R>> {
+    fn gcd(self, rhs: Self) -> Self { poly_gcd(self, rhs) }
+    fn lcm(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() { return Self::zero(); }
+        let g = poly_gcd(self.clone(), rhs.clone());
+        normalize(self.exact_div(&g).unwrap() * rhs)
+    }
+}
+
+impl<R:IntegralDomain+PartialEq+UniquelyFactorizable, T:MonomialTerm> UniquelyFactorizable for ModuleString<R,T,MulRule,BTreeMap<T,R>> {}
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> NoZeroDivisors for ModuleString<R,T,MulRule,BTreeMap<T,R>> {}
@@ -0,0 +1,403 @@
+//!
+//!Contains [GroebnerBasis] and the algorithms for computing it
+//!
+
+use super::*;
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Debug;
+use std::ops::Deref;
+
+use super::division::{lm, sub_shifted, reduce};
+
+//the polynomials over a given monomial type
+type Poly<R,T> = ModuleString<R,T,MulRule,BTreeMap<T,R>>;
+
+//multiplies a polynomial by a monomial
+fn shift<R:Field,T:MonomialTerm>(m: &T, p: &Poly<R,T>) -> Poly<R,T> {
+    p.iter().map(|(r,t)| (r.clone(), m.clone() * t.clone())).collect()
+}
+
+fn monic<R:Field,T:MonomialTerm>(mut p: Poly<R,T>) -> Poly<R,T> {
+    let lc = p.leading_term().unwrap().0.clone();
+    if !lc._is_one() { p /= lc; }
+    p
+}
+
+//the S-polynomial of two monic polynomials
+fn s_poly<R:Field,T:MonomialTerm>(f: &Poly<R,T>, g: &Poly<R,T>) -> Poly<R,T> {
+    let l = lm(f).lcm(lm(g));
+    let mut s = Poly::zero();
+    for (r, t) in f.iter().rev().skip(1) { s += (r.clone(), l.checked_div(lm(f)).unwrap() * t.clone()); }
+    sub_shifted(&mut s, &R::one(), &l.checked_div(lm(g)).unwrap(), g);
+    s
+}
+
+//
+//Keeps track of the critical pairs left to consider and applies Buchberger's criteria to them
+//
+struct Pairs { pairs: HashSet<(usize,usize)> }
+
+impl Pairs {
+
+    fn new() -> Self { Pairs { pairs: HashSet::new() } }
+
+    fn is_empty(&self) -> bool { self.pairs.is_empty() }
+
+    //adds the pairs between the newest element of the basis and all the others
+    fn add_element(&mut self, k: usize) { self.pairs.extend((0..k).map(|i| (i,k))); }
+
+    //removes a pair and determines if its S-polynomial needs to be computed
+    fn take<R,T:MonomialTerm>(&mut self, (i,j): (usize,usize), basis: &[Poly<R,T>]) -> bool {
+        self.pairs.remove(&(i,j));
+        let (lm_i, lm_j) = (lm(&basis[i]), lm(&basis[j]));
+
+        //Buchberger's first criterion: the S-polynomial of coprime leading monomials always reduces to 0
+        if lm_i.coprime(lm_j) { return false; }
+
+        //the chain criterion: if some other leading monomial divides the lcm and the pairs between it
+        //and both of these have already been considered, then this pair is redundant
+        let l = lm_i.lcm(lm_j);
+        let ordered = |a:usize, b:usize| if a < b { (a,b) } else { (b,a) };
+        !(0..basis.len()).any(
+            |k| k!=i && k!=j && lm(&basis[k]).divides(&l) &&
+                !self.pairs.contains(&ordered(i,k)) && !self.pairs.contains(&ordered(j,k))
+        )
+    }
+
+    //the key used to select pairs with the normal strategy: the least lcm by degree and then order
+    fn key<R,T:MonomialTerm>(&self, (i,j): (usize,usize), basis: &[Poly<R,T>]) -> (u32, T) {
+        let l = lm(&basis[i]).lcm(lm(&basis[j]));
+        (l.degree(), l)
+    }
+
+    //the pair with the least lcm, with ties broken by index so that the results are deterministic
+    fn next<R,T:MonomialTerm>(&self, basis: &[Poly<R,T>]) -> (usize,usize) {
+        *self.pairs.iter().min_by(
+            |p, q| self.key(**p, basis).cmp(&self.key(**q, basis)).then_with(|| p.cmp(q))
+        ).unwrap()
+    }
+
+}
+
+//removes the zero polynomials and makes the rest monic
+fn prepare<R:Field,T:MonomialTerm,I:IntoIterator<Item=Poly<R,T>>>(gens: I) -> Vec<Poly<R,T>> {
+    gens.into_iter().filter(|p| !p.is_zero()).map(monic).collect()
+}
+
+//turns a Gröbner basis into the unique reduced Gröbner basis
+fn reduce_basis<R:Field,T:MonomialTerm>(mut basis: Vec<Poly<R,T>>) -> Vec<Poly<R,T>> {
+
+    //since divisors are always smaller, sorting lets us find a minimal basis in one pass
+    basis.sort_by(|f, g| lm(f).cmp(lm(g)));
+    let mut minimal: Vec<Poly<R,T>> = Vec::with_capacity(basis.len());
+    for f in basis {
+        if !minimal.iter().any(|g| lm(g).divides(lm(&f))) { minimal.push(f); }
+    }
+
+    //reduce the tail of each element by the others. Since the leading monomials don't divide
+    //each other, these stay the same, so we can use the unreduced elements as divisors
+    (0..minimal.len()).map(|i| {
+        let mut f = minimal[i].clone();
+        let (c, t) = f.pop_leading_term().unwrap();
+        let others: Vec<_> = minimal.iter().enumerate().filter(|(j,_)| *j!=i).map(|(_,g)| g.clone()).collect();
+        reduce(f, &others) + (c, t)
+    }).collect()
+}
+
+fn buchberger<R:Field,T:MonomialTerm>(mut basis: Vec<Poly<R,T>>) -> Vec<Poly<R,T>> {
+    let mut pairs = Pairs::new();
+    for k in 0..basis.len() { pairs.add_element(k); }
+
+    while !pairs.is_empty() {
+        let pair = pairs.next(&basis);
+        if pairs.take(pair, &basis) {
+            let s = reduce(s_poly(&basis[pair.0], &basis[pair.1]), &basis);
+            if !s.is_zero() {
+                basis.push(monic(s));
+                pairs.add_element(basis.len()-1);
+            }
+        }
+    }
+
+    reduce_basis(basis)
+}
+
+fn f4<R:Field,T:MonomialTerm>(mut basis: Vec<Poly<R,T>>) -> Vec<Poly<R,T>> {
+    let mut pairs = Pairs::new();
+    for k in 0..basis.len() { pairs.add_element(k); }
+
+    while !pairs.is_empty() {
+
+        //select every pair whose lcm has the least degree
+        let d = pairs.pairs.iter().map(|p| pairs.key(*p, &basis).0).min().unwrap();
+        let mut selected: Vec<_> = pairs.pairs.iter().copied().filter(|p| pairs.key(*p, &basis).0==d).collect();
+        selected.sort();
+
+        //the rows to reduce, given as multiples of elements of the basis
+        let mut multiples = BTreeSet::new();
+        for (i,j) in selected {
+            if pairs.take((i,j), &basis) {
+                let l = lm(&basis[i]).lcm(lm(&basis[j]));
+                multiples.insert((l.checked_div(lm(&basis[i])).unwrap(), i));
+                multiples.insert((l.checked_div(lm(&basis[j])).unwrap(), j));
+            }
+        }
+        if multiples.is_empty() { continue; }
+
+        let mut rows: Vec<Poly<R,T>> = multiples.iter().map(|(m,i)| shift(m, &basis[*i])).collect();
+
+        //symbolic preprocessing: add a reducer for every monomial that can be reduced
+        let mut done: BTreeSet<T> = rows.iter().map(|r| lm(r).clone()).collect();
+        let mut todo: BTreeSet<T> = rows.iter().flat_map(|r| r.iter().map(|(_,t)| t.clone()))
+            .filter(|t| !done.contains(t)).collect();
+        while let Some(t) = todo.pop_last() {
+            if let Some((m, g)) = basis.iter().find_map(|g| t.checked_div(lm(g)).map(|m| (m, g))) {
+                let row = shift(&m, g);
+                todo.extend(row.iter().map(|(_,u)| u.clone()).filter(|u| *u!=t && !done.contains(u)));
+                rows.push(row);
+            }
+            done.insert(t);
+        }
+
+        //the leading monomials of every row, including the reducers, are already in the ideal of leading monomials
+        let leading: BTreeSet<T> = rows.iter().map(|r| lm(r).clone()).collect();
+
+        //row-reduce the matrix with the columns ordered from greatest monomial to least
+        let columns: Vec<T> = done.into_iter().rev().collect();
+        let index: BTreeMap<&T,usize> = columns.iter().enumerate().map(|(i,t)| (t,i)).collect();
+        let mut matrix: Vec<Vec<R>> = rows.iter().map(|r| {
+            let mut row = vec![R::zero(); columns.len()];
+            for (c, t) in r.iter() { row[index[t]] = c.clone(); }
+            row
+        }).collect();
+        row_reduce(&mut matrix);
+
+        //the new elements are the rows with leading monomials not among the original ones
+        //and not divisible by the leading monomial of anything already in the basis
+        let old = basis.len();
+        for row in matrix {
+            if let Some(first) = row.iter().position(|c| !c.is_zero()) {
+                let t = &columns[first];
+                if !leading.contains(t) && !basis[..old].iter().any(|g| lm(g).divides(t)) {
+                    let p: Poly<R,T> = row.into_iter().zip(columns.iter().cloned()).filter(|(c,_)| !c.is_zero()).collect();
+                    basis.push(p);
+                    pairs.add_element(basis.len()-1);
+                }
+            }
+        }
+
+    }
+
+    reduce_basis(basis)
+}
+
+//puts a matrix into reduced row echelon form with every pivot equal to one
+fn row_reduce<R:Field>(matrix: &mut [Vec<R>]) {
+    let cols = matrix.first().map_or(0, |r| r.len());
+    let mut rank = 0;
+    for col in 0..cols {
+        let pivot = match (rank..matrix.len()).find(|i| !matrix[*i][col].is_zero()) {
+            Some(i) => i,
+            None => continue,
+        };
+        matrix.swap(rank, pivot);
+
+        let inv = R::one() / matrix[rank][col].clone();
+        for c in matrix[rank][col..].iter_mut() { *c = c.clone() * inv.clone(); }
+
+        let (above, rest) = matrix.split_at_mut(rank);
+        let (pivot_row, below) = rest.split_first_mut().unwrap();
+        for row in above.iter_mut().chain(below.iter_mut()) {
+            let factor = row[col].clone();
+            if factor.is_zero() { continue; }
+            for (c, p) in row[col..].iter_mut().zip(pivot_row[col..].iter()) {
+                *c = c.clone() - factor.clone() * p.clone();
+            }
+        }
+
+        rank += 1;
+    }
+}
+
+///
+///The reduced Gröbner basis of an ideal of commutative polynomials over a field
+///
+///A Gröbner basis is a generating set of an ideal where the [leading monomial](Polynomial::leading_monomial)
+///of every element of the ideal is divisible by the leading monomial of some element of the basis.
+///As such, repeatedly dividing by the basis gives a [normal form](GroebnerBasis::normal_form) of any
+///polynomial modulo the ideal, which in turn decides [ideal membership](GroebnerBasis::contains)
+///and whether or not two polynomials are equal in the quotient ring.
+///
+///Furthermore, the basis is _reduced_: each element is monic and none of its terms are divisible
+///by the leading monomial of any other element. This makes the basis unique for each ideal and
+///monomial order, so two ideals are equal exactly when their bases are.
+///
+///Bases can be computed with either Buchberger's algorithm using [`new()`](GroebnerBasis::new) or
+///Faugère's F4 algorithm using [`f4()`](GroebnerBasis::f4), which reduces many S-polynomials at
+///once with linear algebra and is usually faster on larger systems. In both cases, Buchberger's
+///coprime and chain criteria are used to skip unnecessary S-polynomials. This works with any
+///[MonomialTerm], so both [Polynomial]'s and [PackedPolynomial]'s are supported.
+///
+///Finally, if the monomial order is an elimination order, like [Lex] or [Elim], the basis
+///can be used to [eliminate](GroebnerBasis::eliminate) variables from a system of equations.
+///
+///Note that exact coefficients are recommended, since Gröbner basis computation is notoriously
+///unstable with floating point numbers.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use num_traits::Pow;
+///use free_algebra::{Polynomial, GroebnerBasis, Lex};
+///
+///type P = Polynomial<f64, String, Lex>;
+///let x = P::var("x");
+///let y = P::var("y");
+///
+/////the intersection of the unit circle and the line y=x
+///let circle = x.clone().pow(2u32) + y.clone().pow(2u32) - P::one();
+///let line = y.clone() - x.clone();
+///let g = GroebnerBasis::new(vec![circle.clone(), line.clone()]);
+///
+///assert_eq!(g.len(), 2);
+///assert_eq!(g[0].to_string(), "y^2 - 0.5");
+///assert_eq!(g[1].to_string(), "x - y");
+///
+///assert!(g.contains(&(circle.clone() * x.clone() + line.clone() * y.clone())));
+///assert!(!g.contains(&x));
+///assert_eq!(g.normal_form(x.clone() * x.clone()).to_string(), "0.5");
+///
+/////F4 gives the same result
+///assert_eq!(GroebnerBasis::f4(vec![circle, line]), g);
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound="R:Clone, T:Clone"), PartialEq(bound="R:PartialEq, T:Eq"), Eq(bound="R:Eq, T:Eq"))]
+#[derivative(Hash(bound="R:Hash, T:Hash"), Debug(bound="R:Debug, T:Debug"))]
+pub struct GroebnerBasis<R,T:Ord> {
+    basis: Vec<Poly<R,T>>
+}
+
+impl<R:Field,T:MonomialTerm> GroebnerBasis<R,T> {
+
+    ///Computes the reduced Gröbner basis of the ideal generated by the given polynomials using Buchberger's algorithm
+    pub fn new<I:IntoIterator<Item=Poly<R,T>>>(generators: I) -> Self {
+        GroebnerBasis { basis: buchberger(prepare(generators)) }
+    }
+
+    ///Computes the reduced Gröbner basis of the ideal generated by the given polynomials using the F4 algorithm
+    pub fn f4<I:IntoIterator<Item=Poly<R,T>>>(generators: I) -> Self {
+        GroebnerBasis { basis: f4(prepare(generators)) }
+    }
+
+    ///
+    ///Reduces a polynomial by the basis until none of its terms are divisible by any leading monomial
+    ///
+    ///The result is the unique representative of `p`'s equivalence class modulo the ideal.
+    ///
+    pub fn normal_form(&self, p: Poly<R,T>) -> Poly<R,T> { reduce(p, &self.basis) }
+
+    ///Determines if a polynomial is an element of the ideal, ie, if its normal form is zero
+    pub fn contains(&self, p: &Poly<R,T>) -> bool { self.normal_form(p.clone()).is_zero() }
+
+    ///Determines if the ideal contains every polynomial, ie, if the generators have no common zeros
+    pub fn is_unit_ideal(&self) -> bool { self.basis.iter().any(|g| lm(g).is_one()) }
+
+}
+
+impl<R,T:Ord> GroebnerBasis<R,T> {
+
+    ///The elements of the basis in increasing order of their leading monomials
+    pub fn basis(&self) -> &[Poly<R,T>] { &self.basis }
+
+    ///Returns the elements of the basis in increasing order of their leading monomials
+    pub fn into_basis(self) -> Vec<Poly<R,T>> { self.basis }
+
+}
+
+impl<R,T:Ord> Deref for GroebnerBasis<R,T> {
+    type Target = [Poly<R,T>];
+    fn deref(&self) -> &[Poly<R,T>] { &self.basis }
+}
+
+impl<R,T:Ord> IntoIterator for GroebnerBasis<R,T> {
+    type Item = Poly<R,T>;
+    type IntoIter = ::std::vec::IntoIter<Poly<R,T>>;
+    fn into_iter(self) -> Self::IntoIter { self.basis.into_iter() }
+}
+
+impl<R:Field,V:Ord+Clone,O> GroebnerBasis<R,Monomial<V,O>> where O:for<'a> MonomialOrder<&'a V> {
+
+    ///
+    ///The elements of the basis that don't contain any of the given variables
+    ///
+    ///If the monomial order eliminates these variables, like [Lex] when they are the least ones,
+    ///then this is the reduced Gröbner basis of the ideal's intersection with the polynomials in the
+    ///other variables. In other words, it describes the projection of the solutions onto the
+    ///remaining variables.
+    ///
+    ///# Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use num_traits::Pow;
+    ///use free_algebra::{Polynomial, GroebnerBasis, Lex};
+    ///
+    ///type P = Polynomial<f64, char, Lex>;
+    ///let t = P::var('t');
+    ///let x = P::var('x');
+    ///let y = P::var('y');
+    ///
+    /////the implicit equation of the parametric curve (t^2, t^3)
+    ///let g = GroebnerBasis::new(vec![x - t.clone().pow(2u32), y - t.pow(3u32)]);
+    ///let curve = g.eliminate(&[&'t']);
+    ///
+    ///assert_eq!(curve.len(), 1);
+    ///assert_eq!(curve[0].to_string(), "x^3 - y^2");
+    ///
+    ///```
+    ///
+    pub fn eliminate<Q:Ord+?Sized>(&self, vars: &[&Q]) -> Vec<Poly<R,Monomial<V,O>>> where V:Borrow<Q> {
+        self.basis.iter().filter(
+            |g| g.iter().all(|(_,m)| vars.iter().all(|v| m.exponent(*v)==0))
+        ).cloned().collect()
+    }
+
+}
+
+impl<R:Field, const N: usize, W:PackedWord, O:MonomialOrder<usize>> GroebnerBasis<R,PackedMonomial<N,W,O>> {
+
+    ///
+    ///The elements of the basis that don't contain any of the variables `0..k`
+    ///
+    ///If the monomial order eliminates these variables, like [Lex] or [`Elim<k>`](Elim), then this
+    ///is the reduced Gröbner basis of the ideal's intersection with the polynomials in the
+    ///other variables.
+    ///
+    ///# Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use num_traits::Pow;
+    ///use free_algebra::{PackedPolynomial, GroebnerBasis, Elim};
+    ///
+    ///type P = PackedPolynomial<f64, 3, u64, Elim<1>>;
+    ///let t = P::var(0);
+    ///let x = P::var(1);
+    ///let y = P::var(2);
+    ///
+    ///let g = GroebnerBasis::f4(vec![x - t.clone().pow(2u32), y - t.pow(3u32)]);
+    ///let curve = g.eliminate(1);
+    ///
+    ///assert_eq!(curve.len(), 1);
+    ///assert_eq!(curve[0].to_string(), "x1^3 - x2^2");
+    ///
+    ///```
+    ///
+    pub fn eliminate(&self, k: usize) -> Vec<Poly<R,PackedMonomial<N,W,O>>> {
+        self.basis.iter().filter(
+            |g| g.iter().all(|(_,m)| (0..k).all(|i| m.exponent(i)==0))
+        ).cloned().collect()
+    }
+
+}
@@ -0,0 +1,313 @@
+//!
+//!Contains the interpolation of polynomials from their values
+//!
+
+use super::*;
+use super::factor::XorShift;
+use super::rational_function::poly_eval;
+
+//panics if any two of the points share the same x-coordinate
+fn check_distinct<R:PartialEq>(points: &[(R, R)]) {
+    for (i, (x, _)) in points.iter().enumerate() {
+        if points[..i].iter().any(|(y, _)| x==y) {
+            panic!("attempted to interpolate through two points with the same x-coordinate");
+        }
+    }
+}
+
+impl<R:Field+PartialEq> UniPoly<R> {
+
+    ///
+    ///Finds the polynomial of least degree through the given points using Lagrange's formula
+    ///
+    ///The result is the sum of the `yᵢ ∏(x - xⱼ)/(xᵢ - xⱼ)` over `j≠i`, computed in `O(n²)` time by
+    ///dividing the product of all the `x - xⱼ` by each factor in turn.
+    ///
+    ///## Panics
+    ///If two of the points have the same x-coordinate
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::{UniPoly, Rational, ModuleString, AddRule};
+    ///
+    ///let r = |n: i64| Rational::from(n);
+    ///let p = UniPoly::lagrange_interpolation(&[(r(0), r(1)), (r(1), r(3)), (r(2), r(7)), (r(-1), r(1))]);
+    ///
+    ///assert_eq!(p.to_string(), "x^2 + x + 1");
+    ///
+    /////as with every UniPoly, the result can be used as a polynomial in the monoid ring over the exponents
+    ///let q = ModuleString::<Rational,u32,AddRule>::from(p);
+    ///assert_eq!(q, ModuleString::zero() + (r(1), 2) + (r(1), 1) + (r(1), 0));
+    ///
+    ///```
+    ///
+    pub fn lagrange_interpolation(points: &[(R, R)]) -> Self {
+        check_distinct(points);
+
+        //the product of all the x - xⱼ
+        let master = points.iter().fold(Self::one(), |acc, (x, _)| acc * Self::new(vec![-x.clone(), R::one()]));
+
+        let mut p = Self::zero();
+        for (i, (x, y)) in points.iter().enumerate() {
+            let weight = points.iter().enumerate()
+                .filter(|(j, _)| *j!=i)
+                .fold(R::one(), |acc, (_, (z, _))| acc * (x.clone() - z.clone()));
+            let basis = master.clone().div_rem(Self::new(vec![-x.clone(), R::one()])).0;
+            p += basis * (y.clone() / weight);
+        }
+        p
+    }
+
+    ///
+    ///Finds the polynomial of least degree through the given points using Newton's divided differences
+    ///
+    ///This gives the same result as [Lagrange interpolation](UniPoly::lagrange_interpolation), but
+    ///the coefficients in the Newton basis `∏(x - xⱼ)` for `j<k` are computed first, so points can be
+    ///added without starting over by calling [newton_coefficients](UniPoly::newton_coefficients) and
+    ///[from_newton_form](UniPoly::from_newton_form) directly.
+    ///
+    ///## Panics
+    ///If two of the points have the same x-coordinate
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::{UniPoly, Zp};
+    ///
+    ///type F = Zp<101>;
+    ///
+    /////the values of 3x^3 - 2 at 0, 1, 2, and 3 modulo 101
+    ///let points: Vec<_> = (0..4).map(|x| (F::from(x), F::from(3*x*x*x - 2))).collect();
+    ///let p = UniPoly::newton_interpolation(&points);
+    ///
+    ///assert_eq!(p, UniPoly::from(vec![F::from(-2), F::from(0), F::from(0), F::from(3)]));
+    ///assert_eq!(p, UniPoly::lagrange_interpolation(&points));
+    ///
+    ///```
+    ///
+    pub fn newton_interpolation(points: &[(R, R)]) -> Self {
+        let xs: Vec<R> = points.iter().map(|(x, _)| x.clone()).collect();
+        Self::from_newton_form(&Self::newton_coefficients(points), &xs)
+    }
+
+    ///
+    ///Computes the divided differences `f[x₀], f[x₀,x₁], ..., f[x₀,...,xₙ]` of the given points
+    ///
+    ///These are the coefficients of the interpolating polynomial in the Newton basis.
+    ///
+    ///## Panics
+    ///If two of the points have the same x-coordinate
+    ///
+    pub fn newton_coefficients(points: &[(R, R)]) -> Vec<R> {
+        check_distinct(points);
+
+        //update the table of divided differences in place, one column at a time
+        let mut coeffs: Vec<R> = points.iter().map(|(_, y)| y.clone()).collect();
+        for k in 1..points.len() {
+            for i in (k..points.len()).rev() {
+                let dx = points[i].0.clone() - points[i-k].0.clone();
+                coeffs[i] = (coeffs[i].clone() - coeffs[i-1].clone()) / dx;
+            }
+        }
+        coeffs
+    }
+
+    ///
+    ///Expands a polynomial given by its coefficients in the Newton basis of the given nodes
+    ///
+    ///ie. computes `c₀ + c₁(x-x₀) + c₂(x-x₀)(x-x₁) + ...` using Horner's rule, where only the first
+    ///`coeffs.len() - 1` of the nodes are used
+    ///
+    pub fn from_newton_form(coeffs: &[R], nodes: &[R]) -> Self {
+        coeffs.iter().zip(nodes).rev().fold(Self::zero(), |acc, (c, x)| {
+            acc * Self::new(vec![-x.clone(), R::one()]) + Self::constant(c.clone())
+        })
+    }
+
+}
+
+impl<R:Field+PartialEq+AddAssign, N:Natural, S:TermMap<N,R>> ModuleString<R,N,AddRule,S> {
+
+    ///
+    ///Finds the polynomial of least degree through the given points as an element of the monoid
+    ///ring over the exponents using [Lagrange's formula](UniPoly::lagrange_interpolation)
+    ///
+    ///## Panics
+    ///If two of the points have the same x-coordinate
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::{Rational, ModuleString, AddRule};
+    ///
+    ///let r = |n: i64| Rational::from(n);
+    ///let p = ModuleString::<Rational,u32,AddRule>::lagrange_interpolation(&[(r(0), r(1)), (r(1), r(3)), (r(2), r(7))]);
+    ///
+    ///assert_eq!(p, ModuleString::zero() + (r(1), 2) + (r(1), 1) + (r(1), 0));
+    ///assert_eq!(p, ModuleString::newton_interpolation(&[(r(2), r(7)), (r(0), r(1)), (r(1), r(3))]));
+    ///
+    ///```
+    ///
+    pub fn lagrange_interpolation(points: &[(R, R)]) -> Self { UniPoly::lagrange_interpolation(points).into() }
+
+    ///
+    ///Finds the polynomial of least degree through the given points as an element of the monoid
+    ///ring over the exponents using [Newton's divided differences](UniPoly::newton_interpolation)
+    ///
+    ///## Panics
+    ///If two of the points have the same x-coordinate
+    ///
+    pub fn newton_interpolation(points: &[(R, R)]) -> Self { UniPoly::newton_interpolation(points).into() }
+
+}
+
+//uses specialization to sample from the whole field when its size is known
+trait RandomElement: Field {
+    //a pseudorandom nonzero field element
+    fn _random_element(rng: &mut XorShift) -> Self;
+}
+
+impl<R:Field> RandomElement for R {
+    //a fairly small range of integers, since these are all distinct in characteristic zero
+    default fn _random_element(rng: &mut XorShift) -> R { R::one().mul_n(1 + rng.next() % 0x1000) }
+}
+
+impl<const P: u64> RandomElement for Zp<P> {
+    fn _random_element(rng: &mut XorShift) -> Self { Zp::from(1 + rng.next() % (P - 1)) }
+}
+
+fn random_element<R:Field>(rng: &mut XorShift) -> R { R::_random_element(rng) }
+
+//n distinct pseudorandom field elements if they can be found
+fn distinct_elements<R:Field+PartialEq>(n: usize, rng: &mut XorShift) -> Option<Vec<R>> {
+    let mut elements = Vec::with_capacity(n);
+    for _ in 0..(n * 8 + 8) {
+        if elements.len()==n { break; }
+        let x = random_element(rng);
+        if !elements.contains(&x) { elements.push(x); }
+    }
+    if elements.len()==n { Some(elements) } else { None }
+}
+
+//solves the square system `m c = v` using Gaussian elimination if the matrix is invertible
+fn solve<R:Field+PartialEq>(mut m: Vec<Vec<R>>, mut v: Vec<R>) -> Option<Vec<R>> {
+    let n = v.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|i| !m[*i][col].is_zero())?;
+        m.swap(col, pivot);
+        v.swap(col, pivot);
+
+        let inv = m[col][col].clone().inv();
+        let pivot_row = m[col].clone();
+        for i in 0..n {
+            if i==col || m[i][col].is_zero() { continue; }
+            let factor = m[i][col].clone() * inv.clone();
+            for (a, b) in m[i][col..].iter_mut().zip(&pivot_row[col..]) {
+                *a -= factor.clone() * b.clone();
+            }
+            let d = factor * v[col].clone();
+            v[i] -= d;
+        }
+    }
+    Some(v.into_iter().zip(m).enumerate().map(|(i, (x, row))| x / row[i].clone()).collect())
+}
+
+//evaluates a monomial in the first few variables at a point
+fn eval_monomial<R:Field,T:MonomialTerm>(m: &T, vars: &[T::Var], point: &[R]) -> R {
+    vars.iter().zip(point).fold(R::one(), |acc, (v, x)| acc * repeated_squaring(x.clone(), m.exponent_of(v)))
+}
+
+impl<R:Field+IntegralDomain+PartialEq, T:MonomialTerm> ModuleString<R,T,MulRule,BTreeMap<T,R>> {
+
+    ///
+    ///Reconstructs a sparse polynomial from a function that evaluates it
+    ///
+    ///Given the variables, a bound on the degree of the polynomial in each one, and a "black box"
+    ///that evaluates the polynomial at a point with coordinates in the same order as `vars`, this
+    ///finds the polynomial using Zippel's algorithm. The variables are added one at a time, and at
+    ///each step, the monomials found so far are used as a skeleton, so only their coefficients need
+    ///to be solved for. Thus, the number of evaluations is roughly `n*(d+1)*t` for `n` variables,
+    ///degree `d`, and `t` terms, instead of the `(d+1)ⁿ` needed for dense interpolation.
+    ///
+    ///This is a probabilistic algorithm that evaluates at pseudorandom points, and a term
+    ///can be missed if an unlucky point makes its coefficient vanish. To make this unlikely,
+    ///the result is checked at further random points and the whole process is retried on any
+    ///mismatch. If this still fails, [None] is returned. This also happens if the black box
+    ///isn't a polynomial within the degree bound. Failures become more likely as the field gets
+    ///smaller, so a large [prime field](Zp) works best. Over the [rationals](Rational), every
+    ///evaluation point is an integer, but the linear systems can still lead to large intermediate
+    ///values.
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::{Polynomial, Zp};
+    ///
+    ///type F = Zp<1000003>;
+    ///let vars = ["x".to_string(), "y".to_string(), "z".to_string()];
+    ///
+    /////the black box for 3x^4*y - 5x*z^3 + 7
+    ///let f = |p: &[F]| F::from(3)*p[0].pow_u64(4)*p[1] - F::from(5)*p[0]*p[2].pow_u64(3) + F::from(7);
+    ///
+    ///let p = Polynomial::<F>::sparse_interpolation(&vars, 4, f).unwrap();
+    ///assert_eq!(p.to_string(), "3*x^4*y + 999998*x*z^3 + 7");
+    ///
+    ///```
+    ///
+    pub fn sparse_interpolation<F:FnMut(&[R])->R>(vars: &[T::Var], degree: u32, mut black_box: F) -> Option<Self> {
+        let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+        let d = degree as usize;
+
+        'attempt: for _ in 0..4 {
+            let anchors: Vec<R> = (0..vars.len()).map(|_| random_element(&mut rng)).collect();
+
+            //the terms found so far in the variables added so far
+            let mut terms: Vec<(R, T)> = vec![(black_box(&anchors), T::one())];
+
+            for k in 0..vars.len() {
+                let xs = distinct_elements::<R>(d+1, &mut rng)?;
+
+                //find a set of points where the skeleton's monomials are linearly independent
+                let mut system = None;
+                for _ in 0..8 {
+                    let points: Vec<Vec<R>> = terms.iter().map(|_| (0..k).map(|_| random_element(&mut rng)).collect()).collect();
+                    let m: Vec<Vec<R>> = points.iter().map(|p| terms.iter().map(|(_, t)| eval_monomial(t, &vars[..k], p)).collect()).collect();
+                    if solve(m.clone(), vec![R::zero(); terms.len()]).is_some() { system = Some((points, m)); break; }
+                }
+                let (points, m) = match system { Some(s) => s, None => continue 'attempt };
+
+                //for each value of the new variable, solve for the coefficients of the skeleton
+                let mut values = Vec::with_capacity(d+1);
+                for x in xs.iter() {
+                    let v = points.iter().map(|p| {
+                        let coords: Vec<R> = p.iter().cloned().chain(Some(x.clone())).chain(anchors[k+1..].iter().cloned()).collect();
+                        black_box(&coords)
+                    }).collect();
+                    values.push(solve(m.clone(), v)?);
+                }
+
+                //then interpolate each coefficient as a polynomial in the new variable
+                let mut new_terms = Vec::new();
+                for (i, (_, t)) in terms.iter().enumerate() {
+                    let pts: Vec<(R, R)> = xs.iter().zip(values.iter()).map(|(x, c)| (x.clone(), c[i].clone())).collect();
+                    for (e, c) in UniPoly::newton_interpolation(&pts).into_coeffs().into_iter().enumerate() {
+                        if !c.is_zero() { new_terms.push((c, t.clone() * T::var_pow(&vars[k], e as u32))); }
+                    }
+                }
+                terms = new_terms;
+            }
+
+            //check the result at a few more points
+            let p: Self = terms.into_iter().collect();
+            for _ in 0..4 {
+                let point: Vec<R> = (0..vars.len()).map(|_| random_element(&mut rng)).collect();
+                let value = poly_eval(&p, &mut |v: &T::Var| point[vars.iter().position(|w| w==v).unwrap()].clone());
+                if value != black_box(&point) { continue 'attempt; }
+            }
+            return Some(p);
+        }
+
+        None
+    }
+
+}
@@ -0,0 +1,614 @@
+//!
+//!Contains the Laurent polynomials [UniLaurent] and [LaurentPolynomial]
+//!
+
+use super::*;
+use super::univariate::write_poly;
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Div, DivAssign};
+
+///
+///A product of integer powers of commuting variables of type `V`, ie an element of the free abelian group on `V`
+///
+///Like a [Monomial], this is stored as a list of the variables with non-zero exponents sorted by
+///variable, so each element has a unique representation. However, exponents may be negative, so
+///every monomial has an [inverse](Inv) and can be divided by any other.
+///
+///Laurent monomials are compared in lexicographic order with lesser variables being more significant,
+///since the graded orders on [Monomial]'s are no longer well-founded with negative exponents anyway.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::LaurentMonomial;
+///
+///let x = LaurentMonomial::var('x');
+///let y = LaurentMonomial::var('y');
+///
+///let m = x.clone() * x.clone() / y.clone();
+///
+///assert_eq!(m, LaurentMonomial::new(vec![('x', 2), ('y', -1)]));
+///assert_eq!(m.degree(), 1);
+///assert_eq!(m.exponent(&'y'), -1);
+///assert_eq!(m.to_string(), "x^2*y^-1");
+///assert_eq!(m.clone() * m.clone().inv(), LaurentMonomial::one());
+///
+///assert!(m > x && x > y && y > LaurentMonomial::one() && LaurentMonomial::one() > y.clone().inv());
+///assert_eq!(m.to_monomial::<free_algebra::GrevLex>(), None);
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound="V:Clone"))]
+#[derivative(Default(bound=""))]
+#[derivative(PartialEq(bound="V:PartialEq"), Eq(bound="V:Eq"), Hash(bound="V:Hash"))]
+#[derivative(Debug(bound="V:Debug"))]
+pub struct LaurentMonomial<V=String> {
+    exps: Vec<(V,i32)>
+}
+
+impl<V:Ord> LaurentMonomial<V> {
+
+    //constructs a monomial from a list that is already sorted without zeros
+    fn from_sorted(exps: Vec<(V,i32)>) -> Self { LaurentMonomial { exps } }
+
+    ///
+    ///Constructs a monomial from a list of variables and their exponents
+    ///
+    ///Variables may be given in any order and may be repeated, in which case their exponents are added
+    ///
+    pub fn new<I:IntoIterator<Item=(V,i32)>>(exps: I) -> Self {
+        let mut exps: Vec<_> = exps.into_iter().collect();
+        exps.sort_by(|(v1,_), (v2,_)| v1.cmp(v2));
+
+        //merge repeated variables, which may cancel out
+        let mut merged: Vec<(V,i32)> = Vec::with_capacity(exps.len());
+        for (v, e) in exps {
+            match merged.last_mut() {
+                Some((v2, e2)) if *v2 == v => *e2 += e,
+                _ => merged.push((v, e)),
+            }
+        }
+        merged.retain(|(_,e)| *e != 0);
+        Self::from_sorted(merged)
+    }
+
+    ///Constructs the monomial consisting of a single variable
+    pub fn var<X:Into<V>>(v: X) -> Self { Self::from_sorted(vec![(v.into(), 1)]) }
+
+    ///Constructs the monomial consisting of a single variable raised to the given power
+    pub fn var_pow<X:Into<V>>(v: X, e: i32) -> Self {
+        Self::from_sorted(if e==0 { vec![] } else { vec![(v.into(), e)] })
+    }
+
+    ///The total degree, ie, the sum of all the exponents
+    pub fn degree(&self) -> i32 { self.exps.iter().map(|(_,e)| e).sum() }
+
+    ///The exponent of a given variable
+    pub fn exponent<Q:Ord+?Sized>(&self, v: &Q) -> i32 where V:Borrow<Q> {
+        self.exps.binary_search_by(|(v2,_)| v2.borrow().cmp(v)).map_or(0, |i| self.exps[i].1)
+    }
+
+    ///Iterates over the variables with non-zero exponents in increasing order of the variables
+    pub fn iter(&self) -> impl Iterator<Item=(&V,i32)> { self.exps.iter().map(|(v,e)| (v,*e)) }
+
+    ///The variables with non-zero exponents in increasing order
+    pub fn vars(&self) -> impl Iterator<Item=&V> { self.exps.iter().map(|(v,_)| v) }
+
+    ///Determines if every exponent is non-negative
+    pub fn is_polynomial(&self) -> bool { self.exps.iter().all(|(_,e)| *e > 0) }
+
+    ///Converts to an ordinary [Monomial] if every exponent is non-negative
+    pub fn to_monomial<O>(&self) -> Option<Monomial<V,O>> where V:Clone {
+        if self.is_polynomial() {
+            Some(Monomial::new(self.exps.iter().map(|(v,e)| (v.clone(), *e as u32))))
+        } else {
+            None
+        }
+    }
+
+    //iterates over the exponents of each variable of either monomial in order
+    fn zip_exponents<'a>(&'a self, rhs: &'a Self) -> impl Iterator<Item=(&'a V,i32,i32)> {
+        let (mut a, mut b) = (self.exps.iter().peekable(), rhs.exps.iter().peekable());
+        ::std::iter::from_fn(move || {
+            match (a.peek(), b.peek()) {
+                (Some((v1,e1)), Some((v2,e2))) => Some(match v1.cmp(v2) {
+                    Ordering::Less => { a.next(); (v1,*e1,0) },
+                    Ordering::Greater => { b.next(); (v2,0,*e2) },
+                    Ordering::Equal => { a.next(); b.next(); (v1,*e1,*e2) },
+                }),
+                (Some((v1,e1)), None) => { a.next(); Some((v1,*e1,0)) },
+                (None, Some((v2,e2))) => { b.next(); Some((v2,0,*e2)) },
+                (None, None) => None,
+            }
+        })
+    }
+
+    //combines the exponents of two monomials variable by variable
+    fn combine<F:Fn(i32,i32)->i32>(&self, rhs:&Self, f:F) -> Self where V:Clone {
+        Self::from_sorted(
+            self.zip_exponents(rhs).map(|(v,a,b)| (v.clone(), f(a,b))).filter(|(_,e)| *e!=0).collect()
+        )
+    }
+
+    ///The monomial with the least exponent of each variable from either one, as with the GCD of [Monomial]'s
+    pub fn gcd(&self, rhs: &Self) -> Self where V:Clone { self.combine(rhs, Ord::min) }
+
+    ///The monomial with the greatest exponent of each variable from either one, as with the LCM of [Monomial]'s
+    pub fn lcm(&self, rhs: &Self) -> Self where V:Clone { self.combine(rhs, Ord::max) }
+
+}
+
+impl<V:Ord+Clone,O> From<Monomial<V,O>> for LaurentMonomial<V> {
+    fn from(m: Monomial<V,O>) -> Self {
+        Self::from_sorted(m.iter().map(|(v,e)| (v.clone(), e as i32)).collect())
+    }
+}
+
+impl<V:Ord> FromIterator<(V,i32)> for LaurentMonomial<V> {
+    #[inline] fn from_iter<I:IntoIterator<Item=(V,i32)>>(iter: I) -> Self { Self::new(iter) }
+}
+
+impl<V:Ord> PartialOrd for LaurentMonomial<V> {
+    #[inline] fn partial_cmp(&self, rhs:&Self) -> Option<Ordering> { Some(self.cmp(rhs)) }
+}
+
+impl<V:Ord> Ord for LaurentMonomial<V> {
+    fn cmp(&self, rhs:&Self) -> Ordering {
+        self.zip_exponents(rhs).map(|(_,a,b)| a.cmp(&b)).find(|o| o.is_ne()).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<V:Ord+Clone> MulAssociative for LaurentMonomial<V> {}
+impl<V:Ord+Clone> MulCommutative for LaurentMonomial<V> {}
+
+impl<V:Ord+Clone> MulAssign for LaurentMonomial<V> {
+    #[inline] fn mul_assign(&mut self, rhs:Self) { *self = &*self * &rhs }
+}
+impl<V:Ord+Clone> DivAssign for LaurentMonomial<V> {
+    #[inline] fn div_assign(&mut self, rhs:Self) { *self = &*self / &rhs }
+}
+
+impl<V:Ord+Clone> Mul for LaurentMonomial<V> {
+    type Output = Self;
+    #[inline] fn mul(self, rhs:Self) -> Self { &self * &rhs }
+}
+impl<V:Ord+Clone> Div for LaurentMonomial<V> {
+    type Output = Self;
+    #[inline] fn div(self, rhs:Self) -> Self { &self / &rhs }
+}
+
+impl<V:Ord+Clone> Mul for &LaurentMonomial<V> {
+    type Output = LaurentMonomial<V>;
+    #[inline] fn mul(self, rhs:Self) -> LaurentMonomial<V> { self.combine(rhs, |a,b| a+b) }
+}
+impl<V:Ord+Clone> Div for &LaurentMonomial<V> {
+    type Output = LaurentMonomial<V>;
+    #[inline] fn div(self, rhs:Self) -> LaurentMonomial<V> { self.combine(rhs, |a,b| a-b) }
+}
+
+impl<V:Ord+Clone> Inv for LaurentMonomial<V> {
+    type Output = Self;
+    #[inline] fn inv(self) -> Self { Self::from_sorted(self.exps.into_iter().map(|(v,e)| (v,-e)).collect()) }
+}
+
+impl<V:Ord+Clone> One for LaurentMonomial<V> {
+    #[inline] fn one() -> Self { Self::default() }
+    #[inline] fn is_one(&self) -> bool { self.exps.is_empty() }
+}
+
+impl<V:Ord+Clone> Pow<i32> for LaurentMonomial<V> {
+    type Output = Self;
+    fn pow(self, p:i32) -> Self {
+        Self::from_sorted(self.exps.into_iter().filter(|_| p!=0).map(
+            |(v,e)| (v, e.checked_mul(p).expect("Laurent monomial exponent overflowed an i32"))
+        ).collect())
+    }
+}
+
+///
+///Writes the monomial as a product of powers, eg `x^2*y^-1`, or `1` if it has no variables
+///
+///If the "alternate" flag `#` is used, the `*`'s are dropped
+///
+impl<V:Display> Display for LaurentMonomial<V> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+        if self.exps.is_empty() { return write!(f, "1"); }
+        for (i, (v, e)) in self.exps.iter().enumerate() {
+            if i > 0 && !f.alternate() { write!(f, "*")?; }
+            if *e == 1 { write!(f, "{}", v)?; } else { write!(f, "{}^{}", v, e)?; }
+        }
+        Ok(())
+    }
+}
+
+//write the terms of Laurent polynomials from greatest to least
+impl<V:Ord+Display> SortTerms for LaurentMonomial<V> {
+    fn _sort_terms<R,Q:Borrow<Self>>(terms: &mut [(&R,Q)]) { terms.sort_by(|(_,t1),(_,t2)| t2.borrow().cmp(t1.borrow())) }
+}
+
+///
+///A Laurent polynomial in commuting variables of type `V`, ie a polynomial that may have negative exponents
+///
+///This is simply an [OrderedMonoidRing] over the free abelian group of [LaurentMonomial]'s, so
+///all of the arithmetic of [ModuleString] applies. Additionally, any Laurent polynomial can be
+///[divided](ModuleString::div_monomial) by a monomial and [normalized](ModuleString::normalize)
+///as an ordinary [Polynomial] times a monomial.
+///
+///When formatted, the terms are written from greatest to least in lexicographic order.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::{LaurentPolynomial, LaurentMonomial, Polynomial, Rational};
+///
+///let x = LaurentPolynomial::<i64>::var("x");
+///let y = LaurentPolynomial::<i64>::var("y");
+///let m = LaurentMonomial::new(vec![("x".to_string(), 1), ("y".to_string(), 2)]);
+///
+///let p = (x.clone() + y.clone()).div_monomial(&m) - LaurentPolynomial::constant(3);
+///assert_eq!(p.to_string(), "-3 + y^-2 + x^-1*y^-1");
+///assert_eq!((p.min_degree("x"), p.max_degree("x")), (Some(-1), Some(0)));
+///assert_eq!((p.min_total_degree(), p.max_total_degree()), (Some(-2), Some(0)));
+///
+/////p is a polynomial divided by x*y^2
+///let (q, n) = p.normalize();
+///assert_eq!(q.to_string(), "-3*x*y^2 + x + y");
+///assert_eq!(n.to_string(), "x^-1*y^-2");
+///assert_eq!(LaurentPolynomial::from(q).mul_monomial(&n), p);
+///
+/////Laurent polynomials can be evaluated at any non-zero point
+///let p = p.into_iter().map(|(c, m)| (Rational::from(c), m)).collect::<LaurentPolynomial<Rational>>();
+///let value = p.eval(|v| if v=="x" { Rational::from(2) } else { Rational::new(1, 3) });
+///assert_eq!(value, Rational::new(15, 2));
+///
+///```
+///
+pub type LaurentPolynomial<R,V=String> = ModuleString<R,LaurentMonomial<V>,MulRule,BTreeMap<LaurentMonomial<V>,R>>;
+
+impl<R,V:Ord> ModuleString<R,LaurentMonomial<V>,MulRule,BTreeMap<LaurentMonomial<V>,R>> {
+
+    ///Constructs the Laurent polynomial consisting of a single variable
+    pub fn var<X:Into<V>>(v: X) -> Self where R:One { LaurentMonomial::var(v).into() }
+
+    ///Constructs a Laurent polynomial consisting only of a constant term
+    pub fn constant(r: R) -> Self where R:One { (r, LaurentMonomial::default()).into() }
+
+    ///The least exponent of the given variable in any term or [None] if this polynomial is zero
+    pub fn min_degree<Q:Ord+?Sized>(&self, v:&Q) -> Option<i32> where V:Borrow<Q> {
+        self.iter().map(|(_,m)| m.exponent(v)).min()
+    }
+
+    ///The greatest exponent of the given variable in any term or [None] if this polynomial is zero
+    pub fn max_degree<Q:Ord+?Sized>(&self, v:&Q) -> Option<i32> where V:Borrow<Q> {
+        self.iter().map(|(_,m)| m.exponent(v)).max()
+    }
+
+    ///The least total degree of any term or [None] if this polynomial is zero
+    pub fn min_total_degree(&self) -> Option<i32> { self.iter().map(|(_,m)| m.degree()).min() }
+
+    ///The greatest total degree of any term or [None] if this polynomial is zero
+    pub fn max_total_degree(&self) -> Option<i32> { self.iter().map(|(_,m)| m.degree()).max() }
+
+    ///Multiplies every term by a monomial
+    pub fn mul_monomial(self, m: &LaurentMonomial<V>) -> Self where V:Clone, R:AddAssign {
+        self.into_iter().map(|(r, t)| (r, &t * m)).collect()
+    }
+
+    ///Divides every term by a monomial
+    pub fn div_monomial(self, m: &LaurentMonomial<V>) -> Self where V:Clone, R:AddAssign {
+        self.into_iter().map(|(r, t)| (r, &t / m)).collect()
+    }
+
+    ///
+    ///Writes this Laurent polynomial as an ordinary [Polynomial] times a monomial
+    ///
+    ///The monomial is the one with the least exponent of each variable over all the terms, so the
+    ///resulting polynomial isn't divisible by any of the variables. Zero is written as `0*1`.
+    ///
+    pub fn normalize(&self) -> (Polynomial<R,V>, LaurentMonomial<V>) where V:Clone, R:AddAssign+Clone {
+        let mut terms = self.iter().map(|(_,m)| m);
+        let low = match terms.next() {
+            Some(first) => terms.fold(first.clone(), |low, m| low.gcd(m)),
+            None => LaurentMonomial::one(),
+        };
+        let p = self.iter().map(|(r, m)| (r.clone(), (m / &low).to_monomial().unwrap())).collect();
+        (p, low)
+    }
+
+    ///
+    ///Evaluates this Laurent polynomial using a function giving the value of each variable
+    ///
+    ///Every variable appearing with a negative exponent must be given a non-zero value.
+    ///
+    pub fn eval<F:FnMut(&V)->R>(&self, mut f: F) -> R where R:Field {
+        let mut values: BTreeMap<&V,(R,Option<R>)> = BTreeMap::new();
+        let mut sum = R::zero();
+        for (r, m) in self.iter() {
+            let mut term = r.clone();
+            for (v, e) in m.iter() {
+                let (x, inv) = values.entry(v).or_insert_with(|| (f(v), None));
+                let base = if e > 0 { x.clone() } else { inv.get_or_insert_with(|| x.clone().inv()).clone() };
+                term *= repeated_squaring(base, e.unsigned_abs() as u64);
+            }
+            sum += term;
+        }
+        sum
+    }
+
+}
+
+impl<R:AddAssign,V:Ord+Clone,O> From<Polynomial<R,V,O>> for LaurentPolynomial<R,V> where O:for<'a> MonomialOrder<&'a V> {
+    fn from(p: Polynomial<R,V,O>) -> Self { p.into_iter().map(|(r, m)| (r, m.into())).collect() }
+}
+
+///
+///A Laurent polynomial in one variable stored densely as a list of coefficients starting from the lowest degree
+///
+///Internally, this is stored as an ordinary [UniPoly] with a non-zero constant term along with the
+///least exponent, so every Laurent polynomial has a unique representation and the
+///[normalization](UniLaurent::normalize) into a polynomial times a power of `x` is immediate.
+///Multiplication uses the same dense algorithms as [UniPoly].
+///
+///A [UniLaurent] can also be converted to and from the monoid ring over its exponents,
+///ie a [ModuleString] using the [AddRule] over some [Integer] type.
+///
+///# Examples
+///```
+///use free_algebra::{UniLaurent, UniPoly, Rational};
+///
+/////the Kauffman bracket of the Hopf link using the loop value d = -A^2 - A^-2
+///let a = UniLaurent::<i64>::x();
+///let a_inv = UniLaurent::monomial(1, -1);
+///let d = -(&a*&a) - &a_inv*&a_inv;
+///let hopf = &a*&a*&d + UniLaurent::constant(2) + &a_inv*&a_inv*&d;
+///
+///assert_eq!(hopf.to_string(), "-x^4 - x^-4");
+///assert_eq!((hopf.min_degree(), hopf.max_degree()), (Some(-4), Some(4)));
+///assert_eq!(hopf.normalize(), (UniPoly::from(vec![-1, 0, 0, 0, 0, 0, 0, 0, -1]), -4));
+///assert_eq!(hopf.clone().div_x_pow(-4), UniLaurent::from(UniPoly::from(vec![-1, 0, 0, 0, 0, 0, 0, 0, -1])));
+///
+/////evaluating requires inverting x
+///let hopf = hopf.map_coeffs(Rational::from);
+///assert_eq!(hopf.eval(Rational::from(2)), Rational::new(-257, 16));
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(clone_from="true"))]
+#[derivative(Default(bound=""))]
+#[derivative(PartialEq, Eq, Hash, Debug)]
+pub struct UniLaurent<R> {
+    coeffs: UniPoly<R>,
+    low: i32
+}
+
+impl<R> UniLaurent<R> {
+
+    //removes the zero coefficients at either end, adjusting the least exponent to match
+    fn normalized(mut coeffs: Vec<R>, low: i32) -> Self {
+        let zeros = coeffs.iter().take_while(|c| (*c)._is_zero()).count();
+        coeffs.drain(..zeros);
+        let coeffs = UniPoly::new(coeffs);
+        let low = if coeffs.coeffs().is_empty() { 0 } else { low + zeros as i32 };
+        UniLaurent { coeffs, low }
+    }
+
+    ///
+    ///Constructs a Laurent polynomial from its coefficients starting from the term of degree `low`
+    ///
+    ///Any zeros at either end are removed
+    ///
+    pub fn new(coeffs: Vec<R>, low: i32) -> Self { Self::normalized(coeffs, low) }
+
+    ///The coefficients of this Laurent polynomial starting from the [least degree](UniLaurent::min_degree)
+    pub fn coeffs(&self) -> &[R] { self.coeffs.coeffs() }
+
+    ///The least exponent of any term or [None] if this Laurent polynomial is zero
+    pub fn min_degree(&self) -> Option<i32> { self.coeffs.degree().map(|_| self.low) }
+
+    ///The greatest exponent of any term or [None] if this Laurent polynomial is zero
+    pub fn max_degree(&self) -> Option<i32> { self.coeffs.degree().map(|d| self.low + d as i32) }
+
+    ///Clones the coefficient of the term of the given degree
+    pub fn coeff(&self, degree: i32) -> R where R:Zero+Clone {
+        if degree < self.low { R::zero() } else { self.coeffs.coeff((degree - self.low) as usize) }
+    }
+
+    ///Constructs the Laurent polynomial `c*x^n`
+    pub fn monomial(c: R, n: i32) -> Self { Self::new(vec![c], n) }
+
+    ///Constructs the Laurent polynomial with only a constant term
+    pub fn constant(c: R) -> Self { Self::new(vec![c], 0) }
+
+    ///The Laurent polynomial `x`
+    pub fn x() -> Self where R:One { Self::monomial(R::one(), 1) }
+
+    ///Determines if every exponent is non-negative
+    pub fn is_polynomial(&self) -> bool { self.low >= 0 }
+
+    ///
+    ///Writes this Laurent polynomial as `p*x^n` for a [UniPoly] `p` with a non-zero constant term
+    ///
+    ///The result is returned as `(p, n)`, where `n` is the [least degree](UniLaurent::min_degree)
+    ///or zero if this Laurent polynomial is zero.
+    ///
+    pub fn normalize(&self) -> (UniPoly<R>, i32) where R:Clone { (self.coeffs.clone(), self.low) }
+
+    ///Multiplies by `x^n`
+    pub fn mul_x_pow(mut self, n: i32) -> Self {
+        if !self.coeffs.coeffs().is_empty() { self.low += n; }
+        self
+    }
+
+    ///Divides by `x^n`
+    pub fn div_x_pow(self, n: i32) -> Self { self.mul_x_pow(-n) }
+
+    ///Applies a function to each coefficient, eg to change the coefficient ring
+    pub fn map_coeffs<S,F:FnMut(R)->S>(self, f: F) -> UniLaurent<S> {
+        UniLaurent::normalized(self.coeffs.into_coeffs().into_iter().map(f).collect(), self.low)
+    }
+
+    ///
+    ///Evaluates this Laurent polynomial at a point using Horner's rule
+    ///
+    ///The point must be non-zero if there are any terms of negative degree.
+    ///
+    pub fn eval(&self, x: R) -> R where R:Field {
+        let power = if self.low < 0 { x.clone().inv() } else { x.clone() };
+        self.coeffs.eval(x) * repeated_squaring(power, self.low.unsigned_abs() as u64)
+    }
+
+}
+
+impl<R> From<UniPoly<R>> for UniLaurent<R> {
+    #[inline] fn from(p: UniPoly<R>) -> Self { Self::new(p.into_coeffs(), 0) }
+}
+
+impl<R:AddAssign,Z:Integer,S:TermMap<Z,R>> From<UniLaurent<R>> for ModuleString<R,Z,AddRule,S> {
+    fn from(p: UniLaurent<R>) -> Self {
+        let low = p.low;
+        p.coeffs.into_coeffs().into_iter().enumerate()
+            .map(|(i, r)| (r, Z::from_i64(low as i64 + i as i64).expect("degree too large for the exponent type")))
+            .collect()
+    }
+}
+
+///
+///Converts from the monoid ring over some integer exponents
+///
+///## Examples
+///```
+///use maths_traits::algebra::Zero;
+///use free_algebra::{UniLaurent, ModuleString, AddRule};
+///
+///let p = ModuleString::<i32,i32,AddRule>::zero() + (3, 2) + (1, -1);
+///let q = UniLaurent::from(p.clone());
+///
+///assert_eq!(q, UniLaurent::new(vec![1, 0, 0, 3], -1));
+///assert_eq!(ModuleString::from(q), p);
+///
+///```
+///
+impl<R:Zero+Clone,Z:Integer,S:TermMap<Z,R>> From<ModuleString<R,Z,AddRule,S>> for UniLaurent<R> {
+    fn from(p: ModuleString<R,Z,AddRule,S>) -> Self {
+        let terms: Vec<(R,i32)> = p.into_iter()
+            .map(|(r, n)| (r, n.to_i32().expect("exponent too large for a Laurent polynomial")))
+            .collect();
+        let low = terms.iter().map(|(_, n)| *n).min().unwrap_or(0);
+        let mut coeffs = Vec::new();
+        for (r, n) in terms {
+            let i = (n - low) as usize;
+            if coeffs.len() <= i { coeffs.resize(i+1, R::zero()); }
+            coeffs[i] = r;
+        }
+        Self::new(coeffs, low)
+    }
+}
+
+///
+///Formats the Laurent polynomial in terms of `x` from the highest degree term to the lowest
+///
+///This uses the same conventions as [UniPoly], with negative exponents written like `x^-2`.
+///
+impl<R:Display> Display for UniLaurent<R> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result { write_poly(f, self.coeffs.coeffs(), "x", self.low) }
+}
+
+impl<R:AddAssociative> AddAssociative for UniLaurent<R> {}
+impl<R:AddCommutative> AddCommutative for UniLaurent<R> {}
+impl<R:MulAssociative> MulAssociative for UniLaurent<R> {}
+impl<R:MulCommutative> MulCommutative for UniLaurent<R> {}
+impl<R:Distributive> Distributive for UniLaurent<R> {}
+impl<R:NoZeroDivisors> NoZeroDivisors for UniLaurent<R> {}
+
+//the coefficients of both Laurent polynomials padded to start from the same degree
+fn align<R:Zero>(a: UniLaurent<R>, b: UniLaurent<R>) -> (Vec<R>, Vec<R>, i32) {
+    let low = a.low.min(b.low);
+    let pad = |p: UniLaurent<R>| {
+        let shift = (p.low - low) as usize;
+        (0..shift).map(|_| R::zero()).chain(p.coeffs.into_coeffs()).collect::<Vec<_>>()
+    };
+    (pad(a), pad(b), low)
+}
+
+impl<R:AddAssign+Zero> AddAssign for UniLaurent<R> {
+    fn add_assign(&mut self, rhs:Self) {
+        let (mut a, b, low) = align(::std::mem::take(self), rhs);
+        let mut b = b.into_iter();
+        for (c, r) in a.iter_mut().zip(b.by_ref()) { *c += r; }
+        a.extend(b);
+        *self = Self::normalized(a, low);
+    }
+}
+
+impl<R:SubAssign+Neg<Output=R>+Zero> SubAssign for UniLaurent<R> {
+    fn sub_assign(&mut self, rhs:Self) {
+        let (mut a, b, low) = align(::std::mem::take(self), rhs);
+        let mut b = b.into_iter();
+        for (c, r) in a.iter_mut().zip(b.by_ref()) { *c -= r; }
+        a.extend(b.map(R::neg));
+        *self = Self::normalized(a, low);
+    }
+}
+
+impl<R:Semiring> MulAssign for UniLaurent<R> {
+    fn mul_assign(&mut self, rhs:Self) {
+        let lhs = ::std::mem::take(self);
+        let low = lhs.low + rhs.low;
+        *self = Self::normalized((lhs.coeffs * rhs.coeffs).into_coeffs(), low);
+    }
+}
+
+impl<R:MulAssign+Clone> MulAssign<R> for UniLaurent<R> {
+    fn mul_assign(&mut self, rhs:R) {
+        let lhs = ::std::mem::take(self);
+        *self = Self::normalized((lhs.coeffs * rhs).into_coeffs(), lhs.low);
+    }
+}
+
+impl<R:DivAssign+Clone> DivAssign<R> for UniLaurent<R> {
+    fn div_assign(&mut self, rhs:R) {
+        let lhs = ::std::mem::take(self);
+        *self = Self::normalized((lhs.coeffs / rhs).into_coeffs(), lhs.low);
+    }
+}
+
+impl_arith!(impl<R> AddAssign<&Self>.add_assign for UniLaurent<R> where R:Sized);
+impl_arith!(impl<R> SubAssign<&Self>.sub_assign for UniLaurent<R> where R:Sized);
+impl_arith!(impl<R> MulAssign<&Self>.mul_assign for UniLaurent<R> where R:Sized);
+
+impl_arith!(impl<R> MulAssign<&R>.mul_assign for UniLaurent<R> where R:Sized);
+impl_arith!(impl<R> DivAssign<&R>.div_assign for UniLaurent<R> where R:Sized);
+
+impl_arith!(impl<R> Add.add with AddAssign.add_assign for UniLaurent<R> where R:Sized);
+impl_arith!(impl<R> Sub.sub with SubAssign.sub_assign for UniLaurent<R> where R:Sized);
+impl_arith!(impl<R> Mul.mul with MulAssign.mul_assign for UniLaurent<R> where R:Sized);
+impl_arith!(impl<R> Div.div with DivAssign.div_assign for UniLaurent<R> where R:Sized);
+
+impl<R:Neg<Output=R>> Neg for UniLaurent<R> {
+    type Output = Self;
+    fn neg(self) -> Self { UniLaurent { coeffs: -self.coeffs, low: self.low } }
+}
+
+impl<R:Neg<Output=R>+Clone> Neg for &UniLaurent<R> {
+    type Output = UniLaurent<R>;
+    #[inline] fn neg(self) -> UniLaurent<R> { -self.clone() }
+}
+
+impl<R:AddAssign+Zero> Zero for UniLaurent<R> {
+    #[inline] fn zero() -> Self { Self::default() }
+    #[inline] fn is_zero(&self) -> bool { self.coeffs.is_zero() }
+}
+
+impl<R:Semiring+One+PartialEq> One for UniLaurent<R> {
+    #[inline] fn one() -> Self { Self::constant(R::one()) }
+    #[inline] fn is_one(&self) -> bool { self.low==0 && self.coeffs.is_one() }
+}
+
+impl<Z:Natural,R:UnitalSemiring+PartialEq> Pow<Z> for UniLaurent<R> {
+    type Output = Self;
+    fn pow(self, p:Z) -> Self { repeated_squaring(self, p) }
+}
@@ -0,0 +1,85 @@
+//!
+//!Types and algorithms specific to polynomials
+//!
+
+use super::*;
+
+pub(crate) use self::multiply::*;
+pub use self::univariate::*;
+pub use self::prime_field::*;
+pub use self::rational::*;
+pub use self::quotient::*;
+pub use self::laurent::*;
+pub use self::rational_function::*;
+pub use self::recurrence::*;
+pub use self::monomial::*;
+pub use self::multivariate::*;
+pub use self::packed::*;
+pub use self::groebner::*;
+pub use self::noncommutative::*;
+
+//
+//Implements the traits shared by the value types that behave like fields, given their assigning
+//operators and their impls of Zero, One, and Inv
+//
+macro_rules! impl_field_like {
+
+    //the binary operators and folds, with the same bounds as the impls they are built from
+    (@ops impl[$($gen:tt)*] for $ty:ty) => {
+        impl<$($gen)*> Add for $ty where Self:AddAssign { type Output = Self; #[inline] fn add(mut self, rhs: Self) -> Self { self += rhs; self } }
+        impl<$($gen)*> Sub for $ty where Self:SubAssign { type Output = Self; #[inline] fn sub(mut self, rhs: Self) -> Self { self -= rhs; self } }
+        impl<$($gen)*> Mul for $ty where Self:MulAssign { type Output = Self; #[inline] fn mul(mut self, rhs: Self) -> Self { self *= rhs; self } }
+        impl<$($gen)*> Div for $ty where Self:DivAssign { type Output = Self; #[inline] fn div(mut self, rhs: Self) -> Self { self /= rhs; self } }
+
+        impl<$($gen)*> Sum for $ty where Self:Zero {
+            fn sum<I:Iterator<Item=Self>>(iter: I) -> Self { iter.fold(Self::zero(), |a, b| a + b) }
+        }
+        impl<$($gen)*> Product for $ty where Self:One {
+            fn product<I:Iterator<Item=Self>>(iter: I) -> Self { iter.fold(Self::one(), |a, b| a * b) }
+        }
+    };
+
+    //the above along with the marker traits of a field and its divisibility, where every nonzero element is a unit
+    (impl[$($gen:tt)*] for $ty:ty) => {
+        impl_field_like!(@ops impl[$($gen)*] for $ty);
+
+        impl<$($gen)*> AddAssociative for $ty {}
+        impl<$($gen)*> AddCommutative for $ty {}
+        impl<$($gen)*> MulAssociative for $ty {}
+        impl<$($gen)*> MulCommutative for $ty {}
+        impl<$($gen)*> Distributive for $ty {}
+        impl<$($gen)*> NoZeroDivisors for $ty {}
+        impl<$($gen)*> UniquelyFactorizable for $ty {}
+
+        impl<$($gen)*> Divisibility for $ty {
+            #[inline] fn divides(self, rhs: Self) -> bool { !self.is_zero() || rhs.is_zero() }
+            #[inline] fn divide(self, rhs: Self) -> Option<Self> {
+                if self.is_zero() { if rhs.is_zero() { Some(rhs) } else { None } } else { Some(rhs / self) }
+            }
+            #[inline] fn unit(&self) -> bool { !self.is_zero() }
+            #[inline] fn inverse(self) -> Option<Self> { if self.is_zero() { None } else { Some(self.inv()) } }
+        }
+    };
+
+}
+
+mod multiply;
+mod univariate;
+mod prime_field;
+mod rational;
+mod quotient;
+mod laurent;
+mod monomial;
+mod multivariate;
+mod packed;
+mod division;
+mod gcd;
+mod modular;
+mod resultant;
+mod factor;
+mod roots;
+mod rational_function;
+mod interpolation;
+mod recurrence;
+mod groebner;
+mod noncommutative;
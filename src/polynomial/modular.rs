@@ -0,0 +1,212 @@
+//!
+//!Contains Brown's modular algorithm for the GCDs of multivariate polynomials over the integers
+//!
+
+use super::*;
+use super::factor::inv_mod;
+use super::prime_field::{WithPrime, with_prime, LARGE_PRIMES};
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+//the polynomials over a given monomial type
+type Poly<R,T> = ModuleString<R,T,MulRule,BTreeMap<T,R>>;
+
+//the variables are replaced by their indices and ordered lexicographically with the first most significant
+type Mono = Monomial<usize,Lex>;
+type Dense<R> = Poly<R,Mono>;
+
+//writes a polynomial as one in its last variable with coefficients in the others
+fn split_last<const P: u64>(a: &Dense<Zp<P>>, v: usize) -> BTreeMap<Mono, UniPoly<Zp<P>>> {
+    let mut coeffs: BTreeMap<Mono, Vec<Zp<P>>> = BTreeMap::new();
+    for (r, m) in a.iter() {
+        let e = m.exponent(&v) as usize;
+        let c = coeffs.entry(m.checked_div(&Mono::var_pow(&v, e as u32)).unwrap()).or_default();
+        if c.len() <= e { c.resize(e + 1, Zp::zero()); }
+        c[e] = *r;
+    }
+    coeffs.into_iter().map(|(m, c)| (m, UniPoly::new(c))).collect()
+}
+
+//the inverse of split_last()
+fn join_last<const P: u64>(a: &BTreeMap<Mono, UniPoly<Zp<P>>>, v: usize) -> Dense<Zp<P>> {
+    a.iter().flat_map(|(m, c)| c.coeffs().iter().enumerate().filter(|(_, r)| !r.is_zero()).map(
+        move |(e, r)| (*r, m.clone() * Mono::var_pow(&v, e as u32))
+    )).collect()
+}
+
+//substitutes a value for the last variable
+fn eval_last<const P: u64>(a: &BTreeMap<Mono, UniPoly<Zp<P>>>, x: Zp<P>) -> Dense<Zp<P>> {
+    a.iter().map(|(m, c)| (c.eval(x), m.clone())).filter(|(r, _)| !r.is_zero()).collect()
+}
+
+//divides the coefficients of a split polynomial by their GCD
+fn primitive_last<const P: u64>(a: BTreeMap<Mono, UniPoly<Zp<P>>>) -> (UniPoly<Zp<P>>, BTreeMap<Mono, UniPoly<Zp<P>>>) {
+    let c = a.values().fold(UniPoly::zero(), |g, f| g.gcd(f.clone()));
+    let a = a.into_iter().map(|(m, f)| (m, f.div_rem(c.clone()).0)).collect();
+    (c, a)
+}
+
+fn monic<const P: u64>(a: Dense<Zp<P>>) -> Dense<Zp<P>> {
+    match a.leading_term() {
+        Some((lc, _)) => { let inv = lc.inv(); a * inv },
+        None => a,
+    }
+}
+
+//
+//The monic GCD modulo P of two polynomials in the first k variables
+//
+//This writes both polynomials in terms of their last variable, substitutes enough values for it
+//that the GCD of their primitive parts can be interpolated from the GCDs of the results, and
+//recurses on those. Since a bad value can only increase the degree of the GCD, the images with
+//the least leading monomial are kept, and the interpolated result is checked by trial division.
+//
+fn gcd_mod<const P: u64>(a: &Dense<Zp<P>>, b: &Dense<Zp<P>>, k: usize) -> Option<Dense<Zp<P>>> {
+    if a.is_zero() { return Some(monic(b.clone())); }
+    if b.is_zero() { return Some(monic(a.clone())); }
+    if k==0 { return Some(Dense::one()); }
+
+    let v = k - 1;
+    let (ca, sa) = primitive_last(split_last(a, v));
+    let (cb, sb) = primitive_last(split_last(b, v));
+    let content: BTreeMap<_,_> = ::std::iter::once((Mono::one(), ca.gcd(cb))).collect();
+
+    //the GCD of the leading coefficients is a multiple of the leading coefficient of the true GCD
+    let (la, lb) = (sa.values().next_back().unwrap(), sb.values().next_back().unwrap());
+    let gamma = la.clone().gcd(lb.clone());
+
+    let deg = |s: &BTreeMap<Mono, UniPoly<Zp<P>>>| s.values().filter_map(|f| f.degree()).max().unwrap_or(0);
+    let bound = gamma.degree().unwrap() + deg(&sa).min(deg(&sb));
+
+    let mut images: Vec<(Zp<P>, Dense<Zp<P>>)> = Vec::new();
+    for x in (0..P).map(Zp::from) {
+        if la.eval(x).is_zero() || lb.eval(x).is_zero() { continue; }
+
+        let g = gcd_mod(&eval_last(&sa, x), &eval_last(&sb, x), v)?;
+        let lm = g.leading_term().unwrap().1.clone();
+        if lm.is_one() { return Some(join_last(&content, v)); }
+
+        match images.first().map(|(_, h)| lm.cmp(h.leading_term().unwrap().1)) {
+            Some(Ordering::Greater) => continue,
+            Some(Ordering::Less) => images.clear(),
+            _ => (),
+        }
+        images.push((x, g * gamma.eval(x)));
+
+        if images.len() > bound {
+            //interpolate each coefficient in the last variable
+            let terms: BTreeSet<Mono> = images.iter().flat_map(|(_, g)| g.iter().map(|(_, m)| m.clone())).collect();
+            let h = terms.into_iter().map(|m| {
+                let points: Vec<_> = images.iter().map(|(x, g)| (*x, g.get(&m))).collect();
+                (m, UniPoly::newton_interpolation(&points))
+            }).collect();
+
+            let h = join_last(&primitive_last(h).1, v);
+            if a.exact_div(&h).is_some() && b.exact_div(&h).is_some() {
+                return Some(monic(h * join_last(&content, v)));
+            }
+            images.clear();
+        }
+    }
+    None
+}
+
+//the least non-negative residues modulo P of a polynomial with the same leading monomial
+fn reduce<const P: u64>(a: &Dense<i128>) -> Option<Dense<Zp<P>>> {
+    let r: Dense<Zp<P>> = a.iter().map(|(c, m)| (Zp::from(*c), m.clone())).filter(|(c, _)| !c.is_zero()).collect();
+    if r.leading_term().map(|(_, m)| m) == a.leading_term().map(|(_, m)| m) { Some(r) } else { None }
+}
+
+//a multiple of the GCD modulo P whose leading coefficient is gamma
+struct Image<'a> { a: &'a Dense<i128>, b: &'a Dense<i128>, k: usize, gamma: i128 }
+impl WithPrime for Image<'_> {
+    type Output = Option<Dense<i128>>;
+    fn run<const P: u64>(self) -> Self::Output {
+        let g = gcd_mod(&reduce::<P>(self.a)?, &reduce::<P>(self.b)?, self.k)? * Zp::<P>::from(self.gamma);
+        Some(g.into_iter().map(|(r, m)| (r.value() as i128, m)).collect())
+    }
+}
+
+//combines residues modulo m and p into residues modulo m*p using the Chinese remainder theorem
+fn chinese_remainder(h: &Dense<i128>, m: i128, g: &Dense<i128>, p: i128) -> Option<Dense<i128>> {
+    let mp = m.checked_mul(p)?;
+    let inv = inv_mod(m, p);
+    let terms: BTreeSet<&Mono> = h.iter().chain(g.iter()).map(|(_, t)| t).collect();
+    Some(terms.into_iter().map(|t| {
+        let (x, y) = (h.get(t), g.get(t));
+        ((x + m * ((y - x).rem_euclid(p) * inv % p)).rem_euclid(mp), t.clone())
+    }).collect())
+}
+
+//the representative of each coefficient with the least absolute value
+fn symmetric(h: &Dense<i128>, m: i128) -> Dense<i128> {
+    h.iter().map(|(c, t)| (if *c > m/2 { c - m } else { *c }, t.clone())).filter(|(c, _)| *c != 0).collect()
+}
+
+//determines if b divides a, giving up if any intermediate value overflows
+fn divides(b: &Dense<i128>, a: &Dense<i128>) -> bool {
+    let (lc, lm) = match b.leading_term() { Some(t) => t, None => return a.is_zero() };
+    let mut r: BTreeMap<Mono, i128> = a.iter().map(|(c, t)| (t.clone(), *c)).collect();
+    while let Some((t, c)) = r.pop_last() {
+        if c==0 { continue; }
+        let m = match t.checked_div(lm) { Some(m) if c % lc == 0 => m, _ => return false };
+        for (x, s) in b.iter().rev().skip(1) {
+            let y = r.entry(m.clone() * s.clone()).or_insert(0);
+            match (c / lc).checked_mul(*x).and_then(|z| y.checked_sub(z)) {
+                Some(z) => *y = z,
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+//
+//Computes the GCD of two nonzero integer polynomials with Brown's modular algorithm
+//
+//The GCD is computed modulo several large primes and the results are combined with the
+//Chinese remainder theorem, checking each new candidate by trial division. Since a prime can
+//only make the GCD look larger, any candidate that divides both polynomials is the GCD.
+//Everything is done with 128-bit arithmetic, so `None` is returned if that isn't enough to
+//find a candidate, if the GCD doesn't fit in `Z`, or if every prime is unlucky.
+//
+pub(super) fn modular_gcd<Z:Integer, T:MonomialTerm>(a: &Poly<Z,T>, b: &Poly<Z,T>) -> Option<Poly<Z,T>> {
+    let vars: Vec<T::Var> = a.iter().chain(b.iter()).flat_map(|(_, m)| m.variables()).collect::<BTreeSet<_>>().into_iter().collect();
+    let dense = |p: &Poly<Z,T>| -> Option<Dense<i128>> {
+        p.iter().map(|(r, m)| Some((
+            r.to_i128()?, Mono::new(vars.iter().enumerate().map(|(i, v)| (i, m.exponent_of(v))))
+        ))).collect()
+    };
+    let (a, b) = (dense(a)?, dense(b)?);
+
+    let content = a.content().gcd(b.content()).abs();
+    let (a, b) = (a.primitive_part(), b.primitive_part());
+    let gamma = a.leading_term()?.0.gcd(*b.leading_term()?.0).abs();
+
+    let mut modular: Option<(Dense<i128>, i128)> = None;
+    for &p in LARGE_PRIMES {
+        let g = match with_prime(p, Image { a: &a, b: &b, k: vars.len(), gamma }) { Some(g) => g, None => continue };
+
+        let lm = g.leading_term()?.1;
+        let (h, m) = match modular.take() {
+            //primes that give a GCD of larger degree are discarded
+            Some((h, m)) if h.leading_term()?.1 < lm => { modular = Some((h, m)); continue },
+            //once the modulus can't grow, the remaining primes only serve to replace unlucky ones
+            Some((h, m)) if h.leading_term()?.1 == lm => match chinese_remainder(&h, m, &g, p as i128) {
+                Some(h) => (h, m * p as i128),
+                None => { modular = Some((h, m)); continue },
+            },
+            _ => (g, p as i128),
+        };
+
+        let s = symmetric(&h, m).primitive_part();
+        if divides(&s, &a) && divides(&s, &b) {
+            return s.into_iter().map(|(r, t)| Some((
+                Z::from_i128(r.checked_mul(content)?)?, t.iter().fold(T::one(), |x, (i, e)| x * T::var_pow(&vars[*i], e))
+            ))).collect();
+        }
+        modular = Some((h, m));
+    }
+    None
+}
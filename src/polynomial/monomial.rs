@@ -0,0 +1,363 @@
+//!
+//!Contains [Monomial] and the [orders](MonomialOrder) used to compare them
+//!
+
+use super::*;
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+///
+///A total order on monomials compatible with multiplication
+///
+///Implementors are given the total degrees of the two monomials and an iterator over the exponents
+///of every variable appearing in either one, in increasing order of the variables. Each variable is
+///identified by a handle of type `W`, which is a reference to the variable for [Monomial]'s.
+///
+///As is conventional, lesser variables are considered more significant, so that, for instance,
+///`x > y > z` in [Lex] order.
+///
+///# Examples
+///```
+///use free_algebra::{Monomial, Lex, GrLex, GrevLex};
+///
+///let m = Monomial::<_,Lex>::new(vec![('x', 2), ('z', 1)]);
+///let n = Monomial::<_,Lex>::new(vec![('x', 1), ('y', 2)]);
+///let k = Monomial::<_,Lex>::new(vec![('y', 4)]);
+///
+///assert!(m > n && n > k);
+///assert!(k.clone().reorder::<GrLex>() > m.clone().reorder() && m.clone().reorder::<GrLex>() > n.clone().reorder());
+///assert!(k.reorder::<GrevLex>() > n.clone().reorder() && n.reorder::<GrevLex>() > m.reorder());
+///
+///```
+///
+pub trait MonomialOrder<W> {
+    ///Compares two monomials using their total degrees and the exponents of each of their variables
+    fn cmp_monomials<I:Iterator<Item=(W,u32,u32)>>(deg_a:u64, deg_b:u64, exps:I) -> Ordering;
+}
+
+///
+///Lexicographic order: compares the exponents of each variable in turn
+///
+///ie. `x^2 > x*y^5 > x > y^3 > y > 1`
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Lex;
+
+///
+///Graded lexicographic order: compares total degree, then uses [Lex] order to break ties
+///
+///ie. `x*y^5 > y^3 > x^2 > x > y > 1`
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct GrLex;
+
+///
+///Graded reverse lexicographic order: compares total degree, then breaks ties by considering
+///the monomial with the _lesser_ exponent of the last differing variable to be greater
+///
+///ie. `x*y^2 > y^3 > x^2*z > x*y*z` and `x*y > x*z > y*z`
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct GrevLex;
+
+impl<W> MonomialOrder<W> for Lex {
+    fn cmp_monomials<I:Iterator<Item=(W,u32,u32)>>(_:u64, _:u64, exps:I) -> Ordering {
+        exps.map(|(_,a,b)| a.cmp(&b)).find(|o| o.is_ne()).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<W> MonomialOrder<W> for GrLex {
+    fn cmp_monomials<I:Iterator<Item=(W,u32,u32)>>(deg_a:u64, deg_b:u64, exps:I) -> Ordering {
+        deg_a.cmp(&deg_b).then_with(|| Lex::cmp_monomials(deg_a, deg_b, exps))
+    }
+}
+
+impl<W> MonomialOrder<W> for GrevLex {
+    fn cmp_monomials<I:Iterator<Item=(W,u32,u32)>>(deg_a:u64, deg_b:u64, exps:I) -> Ordering {
+        deg_a.cmp(&deg_b).then_with(
+            || exps.filter(|(_,a,b)| a!=b).last().map_or(Ordering::Equal, |(_,a,b)| b.cmp(&a))
+        )
+    }
+}
+
+///
+///An elimination order for the variables indexed below `K`, using `O` to break ties
+///
+///Monomials are first compared by their total degree in the variables `0..K` and then by `O`, so
+///any monomial containing one of those variables is greater than every monomial that doesn't.
+///Hence, the [Gröbner basis](GroebnerBasis) of an ideal with respect to this order contains a basis
+///of the ideal's intersection with the polynomials in the remaining variables. Unlike [Lex], this
+///only needs to separate the two blocks of variables, and so it is usually much faster to compute.
+///
+///This applies to monomials with variables indexed by [usize], like [PackedMonomial]'s and
+///`Monomial<usize,_>`'s.
+///
+///ie. for `K=1`, `x0 > x1^5 > x1*x2 > x2`
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Elim<const K: usize, O=GrevLex>(PhantomData<O>);
+
+impl<const K: usize, W:Borrow<usize>, O:MonomialOrder<W>> MonomialOrder<W> for Elim<K,O> {
+    fn cmp_monomials<I:Iterator<Item=(W,u32,u32)>>(deg_a:u64, deg_b:u64, exps:I) -> Ordering {
+        let exps: Vec<_> = exps.collect();
+        let (elim_a, elim_b) = exps.iter().filter(|(v,_,_)| *v.borrow() < K).fold(
+            (0u64, 0u64), |(da, db), (_,a,b)| (da + *a as u64, db + *b as u64)
+        );
+        elim_a.cmp(&elim_b).then_with(|| O::cmp_monomials(deg_a, deg_b, exps.into_iter()))
+    }
+}
+
+///
+///A commutative monomial that can be used as the terms of the polynomials in algorithms like
+///[Gröbner basis](GroebnerBasis) computation
+///
+///This is implemented for both [Monomial]'s and [PackedMonomial]'s, and most of the methods are
+///the same as the ones they have directly.
+///
+pub trait MonomialTerm: Ord + Clone + MulMonoid + MulCommutative {
+    ///The type used to identify each variable
+    type Var: Ord + Clone;
+
+    ///The variables with non-zero exponents in increasing order
+    fn variables(&self) -> Vec<Self::Var>;
+    ///The exponent of a given variable
+    fn exponent_of(&self, v: &Self::Var) -> u32;
+    ///The monomial consisting of a single variable raised to the given power
+    fn var_pow(v: &Self::Var, e: u32) -> Self;
+
+    ///The total degree, ie, the sum of all the exponents
+    fn degree(&self) -> u32;
+    ///Determines if this monomial divides another
+    fn divides(&self, rhs: &Self) -> bool;
+    ///Divides this monomial by another if it is divisible
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+    ///The least common multiple of two monomials
+    fn lcm(&self, rhs: &Self) -> Self;
+    ///The greatest common divisor of two monomials
+    fn gcd(&self, rhs: &Self) -> Self;
+    ///Determines if two monomials share no variables
+    fn coprime(&self, rhs: &Self) -> bool;
+}
+
+impl<V:Ord+Clone,O> MonomialTerm for Monomial<V,O> where O:for<'a> MonomialOrder<&'a V> {
+    type Var = V;
+
+    #[inline] fn variables(&self) -> Vec<V> { self.vars().cloned().collect() }
+    #[inline] fn exponent_of(&self, v: &V) -> u32 { self.exponent(v) }
+    #[inline] fn var_pow(v: &V, e: u32) -> Self { Self::from_sorted(if e==0 { vec![] } else { vec![(v.clone(), e)] }) }
+
+    #[inline] fn degree(&self) -> u32 { Monomial::degree(self) }
+    #[inline] fn divides(&self, rhs: &Self) -> bool { Monomial::divides(self, rhs) }
+    #[inline] fn checked_div(&self, rhs: &Self) -> Option<Self> { Monomial::checked_div(self, rhs) }
+    #[inline] fn lcm(&self, rhs: &Self) -> Self { Monomial::lcm(self, rhs) }
+    #[inline] fn gcd(&self, rhs: &Self) -> Self { Monomial::gcd(self, rhs) }
+    #[inline] fn coprime(&self, rhs: &Self) -> bool { Monomial::coprime(self, rhs) }
+}
+
+///
+///A product of powers of commuting variables of type `V` ordered by the [MonomialOrder] `O`
+///
+///Internally, this is stored as a list of the variables with non-zero exponents, sorted by
+///variable, and so each monomial has a unique representation regardless of how it was constructed.
+///
+///Monomials are mainly meant to be used as the terms of a [Polynomial], but they also form a
+///commutative monoid on their own, with the usual divisibility operations.
+///
+///# Examples
+///```
+///use maths_traits::algebra::One;
+///use free_algebra::{Monomial, Lex};
+///
+///let x = Monomial::<_,Lex>::var('x');
+///let y = Monomial::<_,Lex>::var('y');
+///
+///let m = x.clone() * x.clone() * y.clone();
+///let n = Monomial::new(vec![('y', 1), ('x', 2)]);
+///
+///assert_eq!(m, n);
+///assert_eq!(m.degree(), 3);
+///assert_eq!(m.exponent(&'x'), 2);
+///assert_eq!(m.exponent(&'z'), 0);
+///assert_eq!(m.to_string(), "x^2*y");
+///assert_eq!(format!("{:#}", m), "x^2y");
+///
+///assert!(x > y && m > x && y > Monomial::one());
+///assert_eq!(m.checked_div(&x), Some(&x*&y));
+///assert_eq!(y.checked_div(&x), None);
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound="V:Clone"))]
+#[derivative(Default(bound=""))]
+#[derivative(PartialEq(bound="V:PartialEq"), Eq(bound="V:Eq"), Hash(bound="V:Hash"))]
+#[derivative(Debug(bound="V:Debug"))]
+pub struct Monomial<V=String, O=GrevLex> {
+    exps: Vec<(V,u32)>,
+
+    #[derivative(PartialEq="ignore", Hash="ignore")]
+    #[derivative(Debug="ignore")]
+    order: PhantomData<O>
+}
+
+impl<V:Ord,O> Monomial<V,O> {
+
+    //constructs a monomial from a list that is already sorted without zeros
+    fn from_sorted(exps: Vec<(V,u32)>) -> Self { Monomial { exps, order: PhantomData } }
+
+    ///
+    ///Constructs a monomial from a list of variables and their exponents
+    ///
+    ///Variables may be given in any order and may be repeated, in which case their exponents are added
+    ///
+    pub fn new<I:IntoIterator<Item=(V,u32)>>(exps: I) -> Self {
+        let mut exps: Vec<_> = exps.into_iter().filter(|(_,e)| *e != 0).collect();
+        exps.sort_by(|(v1,_), (v2,_)| v1.cmp(v2));
+
+        //merge repeated variables
+        let mut merged: Vec<(V,u32)> = Vec::with_capacity(exps.len());
+        for (v, e) in exps {
+            match merged.last_mut() {
+                Some((v2, e2)) if *v2 == v => *e2 += e,
+                _ => merged.push((v, e)),
+            }
+        }
+        Self::from_sorted(merged)
+    }
+
+    ///Constructs the monomial consisting of a single variable
+    pub fn var<X:Into<V>>(v: X) -> Self { Self::from_sorted(vec![(v.into(), 1)]) }
+
+    ///Changes the order used to compare this monomial
+    pub fn reorder<O2>(self) -> Monomial<V,O2> { Monomial::from_sorted(self.exps) }
+
+    ///The total degree, ie, the sum of all the exponents
+    pub fn degree(&self) -> u32 { self.exps.iter().map(|(_,e)| e).sum() }
+
+    ///The exponent of a given variable
+    pub fn exponent<Q:Ord+?Sized>(&self, v: &Q) -> u32 where V:Borrow<Q> {
+        self.exps.binary_search_by(|(v2,_)| v2.borrow().cmp(v)).map_or(0, |i| self.exps[i].1)
+    }
+
+    ///Iterates over the variables with non-zero exponents in increasing order of the variables
+    pub fn iter(&self) -> impl Iterator<Item=(&V,u32)> { self.exps.iter().map(|(v,e)| (v,*e)) }
+
+    ///The variables with non-zero exponents in increasing order
+    pub fn vars(&self) -> impl Iterator<Item=&V> { self.exps.iter().map(|(v,_)| v) }
+
+    //iterates over the exponents of each variable of either monomial in order
+    fn zip_exponents<'a>(&'a self, rhs: &'a Self) -> impl Iterator<Item=(&'a V,u32,u32)> {
+        let (mut a, mut b) = (self.exps.iter().peekable(), rhs.exps.iter().peekable());
+        ::std::iter::from_fn(move || {
+            match (a.peek(), b.peek()) {
+                (Some((v1,e1)), Some((v2,e2))) => Some(match v1.cmp(v2) {
+                    Ordering::Less => { a.next(); (v1,*e1,0) },
+                    Ordering::Greater => { b.next(); (v2,0,*e2) },
+                    Ordering::Equal => { a.next(); b.next(); (v1,*e1,*e2) },
+                }),
+                (Some((v1,e1)), None) => { a.next(); Some((v1,*e1,0)) },
+                (None, Some((v2,e2))) => { b.next(); Some((v2,0,*e2)) },
+                (None, None) => None,
+            }
+        })
+    }
+
+    //combines the exponents of two monomials variable by variable
+    fn combine<F:Fn(u32,u32)->u32>(&self, rhs:&Self, f:F) -> Self where V:Clone {
+        Self::from_sorted(
+            self.zip_exponents(rhs).map(|(v,a,b)| (v.clone(), f(a,b))).filter(|(_,e)| *e!=0).collect()
+        )
+    }
+
+    ///Determines if this monomial divides another
+    pub fn divides(&self, rhs: &Self) -> bool {
+        self.zip_exponents(rhs).all(|(_,a,b)| a <= b)
+    }
+
+    ///Divides this monomial by another if it is divisible
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> where V:Clone {
+        if rhs.divides(self) { Some(self.combine(rhs, |a,b| a-b)) } else { None }
+    }
+
+    ///The least common multiple of two monomials
+    pub fn lcm(&self, rhs: &Self) -> Self where V:Clone { self.combine(rhs, Ord::max) }
+
+    ///The greatest common divisor of two monomials
+    pub fn gcd(&self, rhs: &Self) -> Self where V:Clone { self.combine(rhs, Ord::min) }
+
+    ///Determines if two monomials share no variables
+    pub fn coprime(&self, rhs: &Self) -> bool { self.zip_exponents(rhs).all(|(_,a,b)| a==0 || b==0) }
+
+}
+
+impl<V:Ord,O> From<V> for Monomial<V,O> {
+    #[inline] fn from(v: V) -> Self { Self::var(v) }
+}
+
+impl<V:Ord,O> FromIterator<(V,u32)> for Monomial<V,O> {
+    #[inline] fn from_iter<I:IntoIterator<Item=(V,u32)>>(iter: I) -> Self { Self::new(iter) }
+}
+
+impl<V:Ord,O> PartialOrd for Monomial<V,O> where O:for<'a> MonomialOrder<&'a V> {
+    #[inline] fn partial_cmp(&self, rhs:&Self) -> Option<Ordering> { Some(self.cmp(rhs)) }
+}
+
+impl<V:Ord,O> Ord for Monomial<V,O> where O:for<'a> MonomialOrder<&'a V> {
+    fn cmp(&self, rhs:&Self) -> Ordering {
+        O::cmp_monomials(self.degree() as u64, rhs.degree() as u64, self.zip_exponents(rhs))
+    }
+}
+
+impl<V:Ord+Clone,O> MulAssociative for Monomial<V,O> {}
+impl<V:Ord+Clone,O> MulCommutative for Monomial<V,O> {}
+
+impl<V:Ord+Clone,O> MulAssign for Monomial<V,O> {
+    #[inline] fn mul_assign(&mut self, rhs:Self) { *self = &*self * &rhs }
+}
+
+impl<V:Ord+Clone,O> Mul for Monomial<V,O> {
+    type Output = Self;
+    #[inline] fn mul(self, rhs:Self) -> Self { &self * &rhs }
+}
+
+impl<V:Ord+Clone,O> Mul for &Monomial<V,O> {
+    type Output = Monomial<V,O>;
+    #[inline] fn mul(self, rhs:Self) -> Monomial<V,O> { self.combine(rhs, |a,b| a+b) }
+}
+
+impl<V:Ord+Clone,O> One for Monomial<V,O> {
+    #[inline] fn one() -> Self { Self::default() }
+    #[inline] fn is_one(&self) -> bool { self.exps.is_empty() }
+}
+
+impl<Z:Natural,V:Ord+Clone,O> Pow<Z> for Monomial<V,O> {
+    type Output = Self;
+    fn pow(self, p:Z) -> Self {
+        let p = p.to_u32().expect("exponent too large for a monomial");
+        Self::from_sorted(self.exps.into_iter().filter(|_| p!=0).map(
+            |(v,e)| (v, e.checked_mul(p).expect("monomial exponent overflowed a u32"))
+        ).collect())
+    }
+}
+
+///
+///Writes the monomial as a product of powers, eg `x^2*y`, or `1` if it has no variables
+///
+///If the "alternate" flag `#` is used, the `*`'s are dropped
+///
+impl<V:Display,O> Display for Monomial<V,O> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+        if self.exps.is_empty() { return write!(f, "1"); }
+        for (i, (v, e)) in self.exps.iter().enumerate() {
+            if i > 0 && !f.alternate() { write!(f, "*")?; }
+            if *e == 1 { write!(f, "{}", v)?; } else { write!(f, "{}^{}", v, e)?; }
+        }
+        Ok(())
+    }
+}
+
+//write the terms of polynomials from greatest to least
+impl<V:Ord+Display,O> SortTerms for Monomial<V,O> where O:for<'a> MonomialOrder<&'a V> {
+    fn _sort_terms<R,Q:Borrow<Self>>(terms: &mut [(&R,Q)]) { terms.sort_by(|(_,t1),(_,t2)| t2.borrow().cmp(t1.borrow())) }
+}
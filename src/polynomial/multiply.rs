@@ -0,0 +1,307 @@
+//!
+//!Dense multiplication of univariate polynomials
+//!
+//!A [MonoidRing] with the [AddRule] over integer exponents, like `ModuleString<R,u32,AddRule>`, is
+//!exactly a ring of univariate (Laurent) polynomials. For these, whenever both factors are large and
+//!dense enough, [add_mul](ModuleString::add_mul()) (and hence multiplication) copies the
+//!coefficients into arrays and uses one of the following instead of the generic loop over pairs of terms:
+//! * Schoolbook multiplication when the coefficients only form a [Semiring]
+//! * Karatsuba multiplication when the coefficients form a [Ring], except for primitive signed
+//!   integers where the sums of coefficients it multiplies could overflow
+//! * A number-theoretic transform over three primes when the coefficients are primitive signed integers,
+//!   the result is guaranteed not to overflow, and the product has at most `2^23` coefficients
+//!
+//!```
+//!use maths_traits::algebra::Zero;
+//!use free_algebra::{ModuleString, AddRule};
+//!
+//!type Poly<R> = ModuleString<R,i32,AddRule>;
+//!
+//!//multiplies using the generic loop over pairs of terms
+//!fn naive<R:Clone+PartialEq+maths_traits::algebra::Semiring>(p:&Poly<R>, q:&Poly<R>) -> Poly<R> {
+//!    q.iter().map(|(r, t)| p.clone() * (r.clone(), *t)).sum()
+//!}
+//!
+//!//a Laurent polynomial with 200 terms multiplied using the NTT
+//!let p: Poly<i64> = (-100..100).map(|i:i32| ((i*i - 7) as i64, i)).collect();
+//!let q: Poly<i64> = (0..300).map(|i:i32| ((1000 - 3*i) as i64, 2*i)).collect();
+//!assert_eq!(p.clone() * q.clone(), naive(&p, &q));
+//!
+//!//coefficients that could overflow the NTT use Karatsuba's algorithm
+//!let p: Poly<i64> = (0..100).map(|i:i32| (if i==30 { 1<<29 } else { i as i64 + 1 }, i)).collect();
+//!let q: Poly<i64> = (0..100).map(|i:i32| (if i==50 { 1<<29 } else { i as i64 - 20 }, i)).collect();
+//!assert_eq!(p.clone() * q.clone(), naive(&p, &q));
+//!
+//!//unless the sums of coefficients it multiplies could overflow too, even if the product can't
+//!let c = 480_000_000;
+//!let p: Poly<i64> = (0..40).map(|i:i32| (c, i)).collect();
+//!let r = p.clone() * p.clone();
+//!assert_eq!(r.get(&39), 40*c*c);
+//!assert_eq!(r, naive(&p, &p));
+//!
+//!//and naturals use the schoolbook method
+//!let p: ModuleString<u32,u32,AddRule> = (0..50).map(|i:u32| (i + 1, i)).collect();
+//!let q: ModuleString<u32,u32,AddRule> = (0..50).map(|i:u32| (2, 2*i)).collect();
+//!let r = p * q;
+//!assert_eq!(r.get(&0), 2);
+//!assert_eq!(r.get(&10), 2*(1 + 3 + 5 + 7 + 9 + 11));
+//!
+//!```
+//!
+
+use super::*;
+
+//the minimum number of terms in each factor before switching to dense multiplication
+const DENSE_THRESHOLD: usize = 16;
+
+//the maximum ratio between the span of the exponents of a factor and its number of terms
+const DENSE_SPARSITY: usize = 4;
+
+//the shortest factor size before the divide and conquer step in Karatsuba multiplication
+const KARATSUBA_THRESHOLD: usize = 32;
+
+//the shortest factor size before multiplying with the NTT
+const NTT_THRESHOLD: usize = 64;
+
+//the longest transform the primes support, since 998244353 - 1 is only divisible by 2^23
+const NTT_MAX_LEN: usize = 1 << 23;
+
+//use specialization to multiply univariate polynomials densely
+pub(crate) trait DenseAddMul { fn _dense_add_mul(&mut self, a:&Self, b:&Self) -> bool; }
+
+impl<T,R,A:?Sized,S:TermMap<T,R>> DenseAddMul for ModuleString<R,T,A,S> {
+    default fn _dense_add_mul(&mut self, _:&Self, _:&Self) -> bool { false }
+}
+
+impl<T:IntegerSubset,R:Semiring,S:TermMap<T,R>> DenseAddMul for ModuleString<R,T,AddRule,S> {
+    fn _dense_add_mul(&mut self, a:&Self, b:&Self) -> bool {
+
+        //gets the least exponent of a factor if it is dense enough
+        fn lowest<T:IntegerSubset,R,S:TermMap<T,R>>(p:&ModuleString<R,T,AddRule,S>) -> Option<(i64,usize)> {
+            if p.len() < DENSE_THRESHOLD { return None; }
+            let mut exps = p.iter().map(|(_,t)| t.borrow().to_i64());
+            let first = exps.next()??;
+            let (lo, hi) = exps.try_fold((first, first), |(lo,hi), e| e.map(|e| (lo.min(e), hi.max(e))))?;
+            let span = usize::try_from(hi.checked_sub(lo)?).ok()?.checked_add(1)?;
+            if span <= p.len().saturating_mul(DENSE_SPARSITY) { Some((lo, span)) } else { None }
+        }
+
+        //copies the coefficients into an array
+        fn dense<T:IntegerSubset,R:Semiring,S:TermMap<T,R>>(p:&ModuleString<R,T,AddRule,S>, lo:i64, span:usize) -> Vec<R> {
+            let mut coeffs = vec![R::zero(); span];
+            for (r, t) in p.iter() {
+                //the exponents were already checked in lowest()
+                coeffs[(t.borrow().to_i64().unwrap() - lo) as usize] = r.clone();
+            }
+            coeffs
+        }
+
+        let ((lo_a, span_a), (lo_b, span_b)) = match (lowest(a), lowest(b)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return false,
+        };
+
+        //make sure every exponent of the product is representable
+        let lo = match lo_a.checked_add(lo_b) { Some(lo) => lo, None => return false };
+        let hi = (span_a + span_b - 2) as i64;
+        if lo.checked_add(hi).and_then(T::from_i64).is_none() || T::from_i64(lo).is_none() { return false; }
+
+        let product = R::_dense_mul(&dense(a, lo_a, span_a), &dense(b, lo_b, span_b));
+        for (i, r) in product.into_iter().enumerate() {
+            if !r.is_zero() { self.accumulate((r, T::from_i64(lo + i as i64).unwrap())); }
+        }
+        self.clean();
+        true
+    }
+}
+
+//use specialization to pick the fastest multiplication algorithm for the coefficients
+pub(crate) trait DenseMul: Sized { fn _dense_mul(a:&[Self], b:&[Self]) -> Vec<Self>; }
+
+impl<R:Semiring> DenseMul for R {
+    default fn _dense_mul(a:&[R], b:&[R]) -> Vec<R> { schoolbook(a, b) }
+}
+
+impl<R:Semiring+Ring> DenseMul for R {
+    default fn _dense_mul(a:&[R], b:&[R]) -> Vec<R> { karatsuba(a, b) }
+}
+
+macro_rules! impl_dense_mul_int {
+    ($($int:ident)*) => {$(
+        impl DenseMul for $int {
+            fn _dense_mul(a:&[$int], b:&[$int]) -> Vec<$int> {
+                //the NTT is only exact if no coefficient of the product can overflow
+                //and every prime has a root of unity of the transform's order
+                let max = |p:&[$int]| p.iter().map(|x| (*x as i128).unsigned_abs()).max().unwrap_or(0);
+                let bound = max(a).checked_mul(max(b)).and_then(|m| m.checked_mul(a.len().min(b.len()) as u128));
+
+                //every value Karatsuba's algorithm computes is at most twice the product of the sums
+                //of the absolute values of the coefficients
+                let sum = |p:&[$int]| p.iter().map(|x| (*x as i128).unsigned_abs()).sum::<u128>();
+                let karatsuba_bound = sum(a).checked_mul(sum(b)).and_then(|m| m.checked_mul(2));
+
+                match bound {
+                    Some(bound) if a.len().min(b.len()) >= NTT_THRESHOLD && bound <= $int::MAX as u128
+                        && (a.len() + b.len() - 1).next_power_of_two() <= NTT_MAX_LEN => {
+                        let (a, b): (Vec<_>, Vec<_>) = (a.iter().map(|x| *x as i128).collect(), b.iter().map(|x| *x as i128).collect());
+                        ntt_mul(&a, &b).into_iter().map(|x| x as $int).collect()
+                    },
+                    _ => match karatsuba_bound {
+                        Some(bound) if bound <= $int::MAX as u128 => karatsuba(a, b),
+                        _ => schoolbook(a, b),
+                    },
+                }
+            }
+        }
+    )*}
+}
+
+impl_dense_mul_int!(i8 i16 i32 i64 isize);
+
+//multiplies every pair of coefficients
+fn schoolbook<R:Semiring>(a:&[R], b:&[R]) -> Vec<R> {
+    if a.is_empty() || b.is_empty() { return Vec::new(); }
+    let mut c = vec![R::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            c[i+j] += x.clone() * y.clone();
+        }
+    }
+    c
+}
+
+//adds the coefficients of b to a starting at the given index
+fn add_at<R:Semiring>(a:&mut [R], b:Vec<R>, index:usize) {
+    for (x, y) in a[index..].iter_mut().zip(b) { *x += y; }
+}
+
+//adds two lists of coefficients, padding the shorter one with zeros
+fn sum<R:Semiring>(a:&[R], b:&[R]) -> Vec<R> {
+    let mut c = a.to_vec();
+    if c.len() < b.len() { c.resize(b.len(), R::zero()); }
+    add_at(&mut c, b.to_vec(), 0);
+    c
+}
+
+//multiplies using Karatsuba's algorithm, preserving the order of each product of coefficients
+fn karatsuba<R:Semiring+Ring>(a:&[R], b:&[R]) -> Vec<R> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD { return schoolbook(a, b); }
+
+    let mut c = vec![R::zero(); a.len() + b.len() - 1];
+
+    //if one factor is much shorter, multiply it with equally sized blocks of the other
+    if a.len() > 2*b.len() {
+        for (i, block) in a.chunks(b.len()).enumerate() { add_at(&mut c, karatsuba(block, b), i*b.len()); }
+        return c;
+    }
+    if b.len() > 2*a.len() {
+        for (i, block) in b.chunks(a.len()).enumerate() { add_at(&mut c, karatsuba(a, block), i*a.len()); }
+        return c;
+    }
+
+    //split both factors in two at the same degree
+    let m = a.len().max(b.len()).div_ceil(2);
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let (b0, b1) = b.split_at(m.min(b.len()));
+
+    //(a0 + a1*x^m)(b0 + b1*x^m) = a0*b0 + ((a0+a1)(b0+b1) - a0*b0 - a1*b1)*x^m + a1*b1*x^2m
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let mut z1 = karatsuba(&sum(a0, a1), &sum(b0, b1));
+    for (x, y) in z1.iter_mut().zip(z0.iter()) { *x -= y.clone(); }
+    for (x, y) in z1.iter_mut().zip(z2.iter()) { *x -= y.clone(); }
+
+    //the product of the sums may have trailing zeros past the end of the result
+    z1.truncate(c.len() - m);
+
+    add_at(&mut c, z0, 0);
+    add_at(&mut c, z1, m);
+    add_at(&mut c, z2, 2*m);
+    c
+}
+
+//NTT-friendly primes of the form k*2^n + 1 with 3 as a primitive root
+const PRIMES: [u64; 3] = [998_244_353, 167_772_161, 469_762_049];
+
+fn pow_mod(mut b:u64, mut e:u64, p:u64) -> u64 {
+    let mut r = 1;
+    b %= p;
+    while e > 0 {
+        if e & 1 == 1 { r = r * b % p; }
+        b = b * b % p;
+        e >>= 1;
+    }
+    r
+}
+
+//an in-place iterative number-theoretic transform modulo p
+fn ntt(a:&mut [u64], p:u64, invert:bool) {
+    let n = a.len();
+
+    //bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 { j ^= bit; bit >>= 1; }
+        j ^= bit;
+        if i < j { a.swap(i, j); }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let w = pow_mod(3, (p-1) / len as u64, p);
+        let w = if invert { pow_mod(w, p-2, p) } else { w };
+        for block in a.chunks_mut(len) {
+            let mut wk = 1;
+            let (lo, hi) = block.split_at_mut(len/2);
+            for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                let (u, v) = (*x, *y * wk % p);
+                *x = (u + v) % p;
+                *y = (u + p - v) % p;
+                wk = wk * w % p;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, p-2, p);
+        for x in a.iter_mut() { *x = *x * n_inv % p; }
+    }
+}
+
+//multiplies two integer polynomials whose product's coefficients are less than 2^63 in magnitude
+fn ntt_mul(a:&[i128], b:&[i128]) -> Vec<i128> {
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    assert!(n <= NTT_MAX_LEN, "attempted a number-theoretic transform longer than the primes support");
+
+    //multiply modulo each prime
+    let residues: Vec<Vec<u64>> = PRIMES.iter().map(|&p| {
+        let reduce = |x:&i128| x.rem_euclid(p as i128) as u64;
+        let mut fa: Vec<u64> = a.iter().map(reduce).collect();
+        let mut fb: Vec<u64> = b.iter().map(reduce).collect();
+        fa.resize(n, 0);
+        fb.resize(n, 0);
+        ntt(&mut fa, p, false);
+        ntt(&mut fb, p, false);
+        for (x, y) in fa.iter_mut().zip(fb) { *x = *x * y % p; }
+        ntt(&mut fa, p, true);
+        fa
+    }).collect();
+
+    //recombine the residues with Garner's algorithm
+    let [p0, p1, p2] = PRIMES;
+    let inv01 = pow_mod(p0, p1-2, p1);
+    let inv012 = pow_mod(p0 * p1 % p2, p2-2, p2);
+    let m = p0 as i128 * p1 as i128 * p2 as i128;
+
+    (0..len).map(|i| {
+        let (r0, r1, r2) = (residues[0][i], residues[1][i], residues[2][i]);
+        let x1 = (r1 + p1 - r0 % p1) % p1 * inv01 % p1;
+        let x01 = (r0 + p0 * x1) % p2;
+        let x2 = (r2 + p2 - x01) % p2 * inv012 % p2;
+        let x = r0 as i128 + p0 as i128 * x1 as i128 + (p0 as i128 * p1 as i128) * x2 as i128;
+        if x > m/2 { x - m } else { x }
+    }).collect()
+}
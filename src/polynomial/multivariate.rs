@@ -0,0 +1,73 @@
+//!
+//!Contains [Polynomial] and its basic operations
+//!
+
+use super::*;
+
+///
+///A polynomial in commuting variables of type `V` with terms ordered by the [MonomialOrder] `O`
+///
+///This is simply a [MonoidRing] over [Monomial]'s stored in a [BTreeMap], so all of the arithmetic
+///of [ModuleString] applies, and the terms are always kept sorted in the given order. In particular,
+///the [leading term](ModuleString::leading_term), [coefficient](ModuleString::leading_coeff), and
+///[monomial](ModuleString::leading_monomial) are all found in `O(log n)` time.
+///
+///When formatted, the terms are written from greatest to least.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use num_traits::Pow;
+///use free_algebra::{Polynomial, Lex};
+///
+///let x = Polynomial::<i32>::var("x");
+///let y = Polynomial::<i32>::var("y");
+///
+///let p = x.clone().pow(2u32)*y.clone() - (x.clone()*y.clone().pow(3u32))*2 + y.clone() + Polynomial::one();
+///
+///assert_eq!(p.to_string(), "-2*x*y^3 + x^2*y + y + 1");
+///assert_eq!(p.leading_coeff(), Some(&-2));
+///assert_eq!(p.leading_monomial().map(|m| m.to_string()), Some("x*y^3".to_string()));
+///assert_eq!(p.total_degree(), Some(4));
+///assert_eq!(p.degree("x"), Some(2));
+///assert_eq!(p.degree("z"), Some(0));
+///
+///let q: Polynomial<i32,String,Lex> = p.reorder();
+///assert_eq!(q.to_string(), "x^2*y - 2*x*y^3 + y + 1");
+///
+///```
+///
+pub type Polynomial<R,V=String,O=GrevLex> = ModuleString<R,Monomial<V,O>,MulRule,BTreeMap<Monomial<V,O>,R>>;
+
+impl<R,V:Ord,O> ModuleString<R,Monomial<V,O>,MulRule,BTreeMap<Monomial<V,O>,R>> where O:for<'a> MonomialOrder<&'a V> {
+
+    ///Constructs the polynomial consisting of a single variable
+    pub fn var<X:Into<V>>(v: X) -> Self where R:One { Monomial::var(v).into() }
+
+    ///Constructs a polynomial consisting only of a constant term
+    pub fn constant(r: R) -> Self where R:One { (r, Monomial::default()).into() }
+
+    ///The coefficient of the greatest term with respect to the order `O`
+    pub fn leading_coeff(&self) -> Option<&R> { self.leading_term().map(|(r,_)| r) }
+
+    ///The greatest monomial with respect to the order `O`
+    pub fn leading_monomial(&self) -> Option<&Monomial<V,O>> { self.leading_term().map(|(_,m)| m) }
+
+    ///The greatest total degree of any term or [None] if this polynomial is zero
+    pub fn total_degree(&self) -> Option<u32> { self.iter().map(|(_,m)| m.degree()).max() }
+
+    ///The greatest exponent of the given variable in any term or [None] if this polynomial is zero
+    pub fn degree<Q:Ord+?Sized>(&self, v:&Q) -> Option<u32> where V:Borrow<Q> {
+        self.iter().map(|(_,m)| m.exponent(v)).max()
+    }
+
+    ///
+    ///Converts this polynomial to use a different monomial order
+    ///
+    ///This requires every term to be reinserted, and so takes `O(n log n)` time
+    ///
+    pub fn reorder<O2>(self) -> Polynomial<R,V,O2> where O2:for<'a> MonomialOrder<&'a V>, R:AddAssign {
+        self.into_iter().map(|(r, m)| (r, m.reorder())).collect()
+    }
+
+}
@@ -0,0 +1,284 @@
+//!
+//!Contains [NcGroebnerBasis] for two-sided ideals of a [FreeAlgebra]
+//!
+
+use super::*;
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::Deref;
+
+//a word in the free monoid ordered by length and then lexicographically
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+struct Word<T>(Vec<T>);
+
+impl<T:Ord> PartialOrd for Word<T> {
+    fn partial_cmp(&self, rhs:&Self) -> Option<Ordering> { Some(self.cmp(rhs)) }
+}
+
+impl<T:Ord> Ord for Word<T> {
+    fn cmp(&self, rhs:&Self) -> Ordering { self.0.len().cmp(&rhs.0.len()).then_with(|| self.0.cmp(&rhs.0)) }
+}
+
+impl<T:Clone> Word<T> {
+    //concatenates three words
+    fn sandwich(a: &[T], b: &[T], c: &[T]) -> Self { Word(a.iter().chain(b).chain(c).cloned().collect()) }
+}
+
+//the elements of the free algebra ordered by their words
+type Poly<R,T> = OrderedFreeModule<R,Word<T>>;
+
+#[inline] fn lm<R,T:Ord>(p: &Poly<R,T>) -> &[T] { &p.leading_term().unwrap().1.0 }
+
+//finds the first position of a word within another
+fn find<T:Eq>(word: &[T], sub: &[T]) -> Option<usize> {
+    if sub.len() > word.len() { return None; }
+    (0..=word.len()-sub.len()).find(|i| word[*i..*i+sub.len()] == *sub)
+}
+
+//subtracts `c*a*g*b` from `p`, skipping the leading term of `g`
+fn sub_sandwich<R:Field,T:Ord+Clone>(p: &mut Poly<R,T>, c: &R, a: &[T], g: &Poly<R,T>, b: &[T]) {
+    for (r, w) in g.iter().rev().skip(1) {
+        *p -= (c.clone() * r.clone(), Word::sandwich(a, &w.0, b));
+    }
+}
+
+fn monic<R:Field,T:Ord>(mut p: Poly<R,T>) -> Poly<R,T> {
+    let lc = p.leading_term().unwrap().0.clone();
+    if !lc._is_one() { p /= lc; }
+    p
+}
+
+//fully reduces a polynomial by a list of monic polynomials
+fn reduce<R:Field,T:Ord+Clone>(mut p: Poly<R,T>, rules: &[Poly<R,T>]) -> Poly<R,T> {
+    let mut rem = Poly::zero();
+    while let Some((c, w)) = p.pop_leading_term() {
+        let rule = rules.iter().find_map(|g| find(&w.0, lm(g)).map(|i| (g, i)));
+        match rule {
+            Some((g, i)) => sub_sandwich(&mut p, &c, &w.0[..i], g, &w.0[i+lm(g).len()..]),
+            None => rem += (c, w),
+        }
+    }
+    rem
+}
+
+//
+//An ambiguity between the leading words of two elements, ie, a word that can be reduced in two ways
+//
+//This is stored as the indices of the two elements and the prefixes and suffixes that need to
+//be added to each to make their leading words equal
+//
+struct Obstruction<T> {
+    word_len: usize,
+    left: (usize, Vec<T>, Vec<T>),
+    right: (usize, Vec<T>, Vec<T>),
+}
+
+//lists the ambiguities between the leading words of two elements
+fn obstructions<T:Ord+Clone>(i: usize, u: &[T], j: usize, v: &[T]) -> Vec<Obstruction<T>> {
+    let mut list = Vec::new();
+
+    //overlaps, where a suffix of u is a prefix of v, ie, u = a*s and v = s*b
+    for k in 1..u.len().min(v.len()) {
+        if u[u.len()-k..] == v[..k] {
+            list.push(Obstruction {
+                word_len: u.len() + v.len() - k,
+                left: (i, Vec::new(), v[k..].to_vec()),
+                right: (j, u[..u.len()-k].to_vec(), Vec::new()),
+            });
+        }
+    }
+
+    //inclusions, where u = a*v*b
+    if i != j && v.len() <= u.len() {
+        for k in 0..=u.len()-v.len() {
+            if u[k..k+v.len()] == *v {
+                list.push(Obstruction {
+                    word_len: u.len(),
+                    left: (i, Vec::new(), Vec::new()),
+                    right: (j, u[..k].to_vec(), u[k+v.len()..].to_vec()),
+                });
+            }
+        }
+    }
+
+    list
+}
+
+//removes the redundant elements of a basis and reduces the rest by each other
+fn interreduce<R:Field,T:Ord+Clone>(mut basis: Vec<Poly<R,T>>) -> Vec<Poly<R,T>> {
+    basis.sort_by(|f, g| f.leading_term().unwrap().1.cmp(g.leading_term().unwrap().1));
+    let mut minimal: Vec<Poly<R,T>> = Vec::with_capacity(basis.len());
+    for f in basis {
+        if !minimal.iter().any(|g| find(lm(&f), lm(g)).is_some()) { minimal.push(f); }
+    }
+
+    (0..minimal.len()).map(|i| {
+        let mut f = minimal[i].clone();
+        let (c, w) = f.pop_leading_term().unwrap();
+        let others: Vec<_> = minimal.iter().enumerate().filter(|(j,_)| *j!=i).map(|(_,g)| g.clone()).collect();
+        reduce(f, &others) + (c, w)
+    }).collect()
+}
+
+//runs the Buchberger–Mora completion, returning the basis and whether or not it is complete
+fn complete<R:Field,T:Ord+Clone>(gens: Vec<Poly<R,T>>, max_degree: usize) -> (Vec<Poly<R,T>>, bool) {
+
+    let mut basis = interreduce(gens.into_iter().filter(|p| !p.is_zero()).map(monic).collect());
+    let mut pending = Vec::new();
+    for k in 0..basis.len() { add_obstructions(&basis, k, &mut pending); }
+
+    let mut truncated = false;
+    while !pending.is_empty() {
+
+        //process the smallest ambiguities first so that the basis is correct up to the degree bound
+        let next = (0..pending.len()).min_by_key(|k| pending[*k].word_len).unwrap();
+        let ob: Obstruction<T> = pending.swap_remove(next);
+        if ob.word_len > max_degree { truncated = true; continue; }
+
+        let ((i, a1, b1), (j, a2, b2)) = (&ob.left, &ob.right);
+        let mut s = Poly::zero();
+        sub_sandwich(&mut s, &-R::one(), a1, &basis[*i], b1);
+        sub_sandwich(&mut s, &R::one(), a2, &basis[*j], b2);
+
+        let s = reduce(s, &basis);
+        if !s.is_zero() {
+            basis.push(monic(s));
+            add_obstructions(&basis, basis.len()-1, &mut pending);
+        }
+    }
+
+    (interreduce(basis), !truncated)
+}
+
+//adds the ambiguities between the newest element of the basis and every element including itself
+fn add_obstructions<R,T:Ord+Clone>(basis: &[Poly<R,T>], k: usize, pending: &mut Vec<Obstruction<T>>) {
+    let new = lm(&basis[k]);
+    for (i, old) in basis[..=k].iter().map(lm).enumerate() {
+        pending.extend(obstructions(i, old, k, new));
+        if i != k { pending.extend(obstructions(k, new, i, old)); }
+    }
+}
+
+fn to_ordered<R:AddAssign,T:Ord+Hash+Clone>(p: FreeAlgebra<R,T>) -> Poly<R,T> {
+    p.into_iter().map(|(r, m)| (r, Word(m.iter().cloned().collect()))).collect()
+}
+
+fn to_free<R:AddAssign,T:Ord+Hash>(p: Poly<R,T>) -> FreeAlgebra<R,T> {
+    p.into_iter().map(|(r, w)| (r, w.0.into_iter().collect())).collect()
+}
+
+///
+///A Gröbner basis of a two-sided ideal of a [FreeAlgebra] over a field
+///
+///The words of the free algebra are ordered first by length and then lexicographically using
+///the order on `T`, and the basis is computed by the Buchberger–Mora completion procedure: every
+///ambiguity where the leading words of two elements overlap, or where one contains the other,
+///is resolved by reducing the corresponding S-polynomial and adding any nonzero result to the
+///basis. The final basis is reduced, so each element is monic and none of its words contain
+///the leading word of any other element.
+///
+///Unlike for commutative polynomials, this process need not terminate, so only ambiguities
+///of length up to `max_degree` are considered, and [`is_complete()`](NcGroebnerBasis::is_complete)
+///reports if the bound was ever reached. If not, the basis is a true Gröbner basis and the normal
+///forms are unique for every element of the quotient algebra.
+///
+///Otherwise, the result is only a _truncated_ Gröbner basis. If every relation is homogeneous, it
+///still gives the correct [normal forms](NcGroebnerBasis::normal_form) of any polynomial of degree
+///up to `max_degree`, since an ambiguity of greater length can then only produce elements of
+///greater degree. For inhomogeneous relations, however, those ambiguities can reduce to elements
+///of lower degree, so there is no such guarantee, and the normal forms may fail to be unique even
+///for polynomials of low degree.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::{FreeAlgebra, FreeMonoid, NcGroebnerBasis};
+///
+///let x = FreeAlgebra::<f64,_>::from(FreeMonoid::from('x'));
+///let y = FreeAlgebra::<f64,_>::from(FreeMonoid::from('y'));
+///
+/////the Weyl algebra, where y*x = x*y + 1
+///let weyl = NcGroebnerBasis::new(vec![y.clone()*x.clone() - x.clone()*y.clone() - FreeAlgebra::one()], 10);
+///
+///assert!(weyl.is_complete());
+///assert_eq!(weyl.len(), 1);
+///
+///let p = y.clone() * y.clone() * x.clone();
+///assert_eq!(weyl.normal_form(p.clone()).to_string(), "x*y*y + 2*y");
+///assert!(weyl.equivalent(&p, &(x.clone()*y.clone()*y.clone() + y.clone()*2.0)));
+///
+/////here, the completion never terminates, but since the relation is homogeneous,
+/////the normal forms of low degree are still correct
+///let braid = NcGroebnerBasis::new(vec![x.clone()*y.clone()*x.clone() - y.clone()*x.clone()*y.clone()], 8);
+///
+///assert!(!braid.is_complete());
+///assert!(braid.len() > 1);
+///assert!(braid.equivalent(&(x.clone()*y.clone()*x.clone()*x.clone()), &(y.clone()*x.clone()*y.clone()*x.clone())));
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound="R:Clone, T:Clone"), Debug(bound="R:Debug, T:Debug"))]
+pub struct NcGroebnerBasis<R,T:Ord+Hash> {
+    basis: Vec<FreeAlgebra<R,T>>,
+
+    #[derivative(Debug="ignore")]
+    rules: Vec<Poly<R,T>>,
+    complete: bool,
+}
+
+impl<R:Field,T:Ord+Hash+Clone> NcGroebnerBasis<R,T> {
+
+    ///
+    ///Computes the reduced Gröbner basis of the two-sided ideal generated by the given relations,
+    ///resolving ambiguities of length up to `max_degree`
+    ///
+    pub fn new<I:IntoIterator<Item=FreeAlgebra<R,T>>>(relations: I, max_degree: usize) -> Self {
+        let (rules, complete) = complete(relations.into_iter().map(to_ordered).collect(), max_degree);
+        NcGroebnerBasis { basis: rules.iter().cloned().map(to_free).collect(), rules, complete }
+    }
+
+    ///
+    ///Determines if the completion finished without reaching the degree bound
+    ///
+    ///If this is `true`, then the basis is a true Gröbner basis of the ideal. Otherwise, it is only
+    ///correct up to the degree bound, and only if the relations are homogeneous.
+    ///
+    pub fn is_complete(&self) -> bool { self.complete }
+
+    ///
+    ///Reduces a polynomial by the basis until none of its words contain any of the leading words
+    ///
+    ///If the basis [is complete](NcGroebnerBasis::is_complete), or if the relations are homogeneous
+    ///and `p` has degree at most the degree bound, this is the unique representative of `p` modulo
+    ///the ideal. Otherwise, equivalent polynomials may have different normal forms.
+    ///
+    pub fn normal_form(&self, p: FreeAlgebra<R,T>) -> FreeAlgebra<R,T> {
+        to_free(reduce(to_ordered(p), &self.rules))
+    }
+
+    ///Determines if a polynomial is an element of the ideal, ie, if its normal form is zero
+    pub fn contains(&self, p: &FreeAlgebra<R,T>) -> bool { reduce(to_ordered(p.clone()), &self.rules).is_zero() }
+
+    ///Determines if two polynomials are equal in the quotient by the ideal
+    pub fn equivalent(&self, a: &FreeAlgebra<R,T>, b: &FreeAlgebra<R,T>) -> bool {
+        self.contains(&(a.clone() - b.clone()))
+    }
+
+}
+
+impl<R,T:Ord+Hash> NcGroebnerBasis<R,T> {
+
+    ///The elements of the basis in increasing order of their leading words
+    pub fn basis(&self) -> &[FreeAlgebra<R,T>] { &self.basis }
+
+    ///Returns the elements of the basis in increasing order of their leading words
+    pub fn into_basis(self) -> Vec<FreeAlgebra<R,T>> { self.basis }
+
+}
+
+impl<R,T:Ord+Hash> Deref for NcGroebnerBasis<R,T> {
+    type Target = [FreeAlgebra<R,T>];
+    fn deref(&self) -> &[FreeAlgebra<R,T>] { &self.basis }
+}
@@ -0,0 +1,428 @@
+//!
+//!Contains [PackedMonomial] and [PackedPolynomial]
+//!
+
+use super::*;
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hasher;
+use num_traits::{PrimInt, Unsigned};
+
+///
+///An unsigned integer type that can hold the exponents of a [PackedMonomial]
+///
+pub trait PackedWord: PrimInt + Unsigned + Hash + Debug {}
+
+impl PackedWord for u32 {}
+impl PackedWord for u64 {}
+impl PackedWord for u128 {}
+
+///
+///A monomial in `N` indexed variables with its exponents packed into a single integer of type `W`
+///
+///The bits of `W` are split evenly into `N+1` fields: one for the exponent of each variable and a
+///final field holding the total degree. This way, multiplication is just an integer addition and,
+///since the fields are laid out with the total degree and then the lesser variables in the most
+///significant bits, [Lex] and [GrLex] comparisons reduce to a single integer comparison
+///(and [GrevLex] to only a few more).
+///
+///As such, the total degree is limited to [`max_degree()`](PackedMonomial::max_degree), which is
+///`255` for up to `7` variables in a [u64] and `65535` for up to `7` variables in a [u128]. Exceeding
+///this when multiplying will panic in debug builds, and [`checked_mul()`](PackedMonomial::checked_mul)
+///can be used to check for it beforehand.
+///
+///Variables are indexed from `0` and written as `x0`, `x1`, etc.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::{PackedMonomial, Lex};
+///
+///type M = PackedMonomial<3>;
+///
+///let x = M::var(0);
+///let y = M::var(1);
+///let m = x * x * y;
+///
+///assert_eq!(m, M::new([2, 1, 0]));
+///assert_eq!(m.degree(), 3);
+///assert_eq!(m.exponent(0), 2);
+///assert_eq!(m.exponents(), [2, 1, 0]);
+///assert_eq!(m.to_string(), "x0^2*x1");
+///assert_eq!(format!("{:#}", m), "x0^2x1");
+///assert_eq!(M::max_degree(), 65535);
+///
+///assert!(x > y && m > x && y > M::one());
+///assert_eq!(m.checked_div(&x), Some(x*y));
+///assert_eq!(y.checked_div(&x), None);
+///assert_eq!(m.lcm(&M::var(2)), M::new([2, 1, 1]));
+///
+/////the order can be changed like with any other monomial
+///assert!(M::new([1, 0, 2]) < M::new([0, 2, 1]));
+///assert!(M::new([1, 0, 2]).reorder::<Lex>() > M::new([0, 2, 1]).reorder());
+///
+/////and overflow can be caught
+///let big = PackedMonomial::<7>::new([200, 0, 0, 0, 0, 0, 0]);
+///assert_eq!(big.checked_mul(&big), None);
+///
+///```
+///
+pub struct PackedMonomial<const N: usize, W:PackedWord=u64, O=GrevLex> {
+    bits: W,
+    order: PhantomData<O>
+}
+
+//these are all implemented manually to avoid needing bounds on O
+
+impl<const N: usize, W:PackedWord, O> Clone for PackedMonomial<N,W,O> {
+    #[inline] fn clone(&self) -> Self { *self }
+}
+
+impl<const N: usize, W:PackedWord, O> Copy for PackedMonomial<N,W,O> {}
+
+impl<const N: usize, W:PackedWord, O> PartialEq for PackedMonomial<N,W,O> {
+    #[inline] fn eq(&self, rhs:&Self) -> bool { self.bits == rhs.bits }
+}
+
+impl<const N: usize, W:PackedWord, O> Eq for PackedMonomial<N,W,O> {}
+
+impl<const N: usize, W:PackedWord, O> Hash for PackedMonomial<N,W,O> {
+    #[inline] fn hash<H:Hasher>(&self, state: &mut H) { self.bits.hash(state) }
+}
+
+impl<const N: usize, W:PackedWord, O> PackedMonomial<N,W,O> {
+
+    #[inline] fn from_bits(bits: W) -> Self { PackedMonomial { bits, order: PhantomData } }
+
+    #[inline] fn bits() -> u32 { W::zero().count_zeros() }
+
+    //the number of bits used for each exponent
+    #[inline]
+    fn width() -> u32 {
+        let w = Self::bits() / (N as u32 + 1);
+        assert!(w > 0, "too many variables to pack into {} bits", Self::bits());
+        w
+    }
+
+    #[inline] fn field_mask() -> W { W::max_value() >> (Self::bits() - Self::width()) as usize }
+
+    #[inline] fn shift(i: usize) -> usize { (N - 1 - i) * Self::width() as usize }
+
+    #[inline] fn degree_shift() -> usize { N * Self::width() as usize }
+
+    //the bits of every exponent field except the total degree
+    #[inline]
+    fn var_mask() -> W {
+        if N==0 { W::zero() } else { W::max_value() >> (Self::bits() as usize - Self::degree_shift()) }
+    }
+
+    //the lowest bit of each field above the first, ie, where borrows between fields would end up
+    #[inline]
+    fn borrow_mask() -> W {
+        (1..=N).fold(W::zero(), |m, k| m | (W::one() << (k * Self::width() as usize)))
+    }
+
+    #[inline] fn field(&self, shift: usize) -> u32 {
+        ((self.bits >> shift) & Self::field_mask()).to_u32().unwrap_or(u32::MAX)
+    }
+
+    ///The largest total degree that can be stored
+    pub fn max_degree() -> u32 { Self::field_mask().to_u32().unwrap_or(u32::MAX) }
+
+    ///
+    ///Constructs a monomial from the exponents of each variable
+    ///
+    ///# Panics
+    ///If the total degree is greater than [`max_degree()`](PackedMonomial::max_degree)
+    ///
+    pub fn new(exps: [u32; N]) -> Self {
+        let deg = exps.iter().fold(0u64, |d, e| d + *e as u64);
+        assert!(deg <= Self::max_degree() as u64, "degree {} too large for a packed monomial", deg);
+
+        let mut bits = W::from(deg).unwrap() << Self::degree_shift();
+        for (i, e) in exps.iter().enumerate() {
+            bits = bits | (W::from(*e).unwrap() << Self::shift(i));
+        }
+        Self::from_bits(bits)
+    }
+
+    ///
+    ///Constructs the monomial consisting of the `i`th variable
+    ///
+    ///# Panics
+    ///If `i >= N`
+    ///
+    pub fn var(i: usize) -> Self {
+        assert!(i < N, "variable index {} out of range for {} variables", i, N);
+        Self::from_bits((W::one() << Self::degree_shift()) | (W::one() << Self::shift(i)))
+    }
+
+    ///Changes the order used to compare this monomial
+    #[inline] pub fn reorder<O2>(self) -> PackedMonomial<N,W,O2> { PackedMonomial::from_bits(self.bits) }
+
+    ///The total degree, ie, the sum of all the exponents
+    #[inline] pub fn degree(&self) -> u32 { self.field(Self::degree_shift()) }
+
+    ///
+    ///The exponent of the `i`th variable
+    ///
+    ///# Panics
+    ///If `i >= N`
+    ///
+    #[inline]
+    pub fn exponent(&self, i: usize) -> u32 {
+        assert!(i < N, "variable index {} out of range for {} variables", i, N);
+        self.field(Self::shift(i))
+    }
+
+    ///The exponents of every variable
+    pub fn exponents(&self) -> [u32; N] {
+        let mut exps = [0; N];
+        for (i, e) in exps.iter_mut().enumerate() { *e = self.field(Self::shift(i)); }
+        exps
+    }
+
+    //iterates over the exponents of each variable of both monomials in order
+    fn zip_exponents(self, rhs: Self) -> impl Iterator<Item=(usize,u32,u32)> {
+        (0..N).map(move |i| (i, self.field(Self::shift(i)), rhs.field(Self::shift(i))))
+    }
+
+    fn combine<F:Fn(u32,u32)->u32>(&self, rhs: &Self, f:F) -> Self {
+        let mut exps = [0; N];
+        for (e, (_,a,b)) in exps.iter_mut().zip(self.zip_exponents(*rhs)) { *e = f(a,b); }
+        Self::new(exps)
+    }
+
+    ///Multiplies two monomials, returning [None] if the total degree would be too large
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        //every exponent is at most the degree, so only the degree can overflow
+        if self.degree() as u64 + rhs.degree() as u64 <= Self::max_degree() as u64 {
+            Some(Self::from_bits(self.bits + rhs.bits))
+        } else {
+            None
+        }
+    }
+
+    ///Determines if this monomial divides another
+    #[inline]
+    pub fn divides(&self, rhs: &Self) -> bool {
+        //the subtraction borrows between fields exactly when some exponent is too large
+        rhs.bits >= self.bits && ((rhs.bits - self.bits) ^ rhs.bits ^ self.bits) & Self::borrow_mask() == W::zero()
+    }
+
+    ///Divides this monomial by another if it is divisible
+    #[inline]
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.divides(self) { Some(Self::from_bits(self.bits - rhs.bits)) } else { None }
+    }
+
+    ///The least common multiple of two monomials
+    ///
+    ///# Panics
+    ///If the total degree of the result is greater than [`max_degree()`](PackedMonomial::max_degree)
+    ///
+    pub fn lcm(&self, rhs: &Self) -> Self { self.combine(rhs, Ord::max) }
+
+    ///The greatest common divisor of two monomials
+    pub fn gcd(&self, rhs: &Self) -> Self { self.combine(rhs, Ord::min) }
+
+    ///Determines if two monomials share no variables
+    pub fn coprime(&self, rhs: &Self) -> bool { self.zip_exponents(*rhs).all(|(_,a,b)| a==0 || b==0) }
+
+}
+
+impl<const N: usize, W:PackedWord, O:MonomialOrder<usize>> MonomialTerm for PackedMonomial<N,W,O> {
+    type Var = usize;
+
+    #[inline] fn variables(&self) -> Vec<usize> { (0..N).filter(|i| self.exponent(*i)!=0).collect() }
+    #[inline] fn exponent_of(&self, v: &usize) -> u32 { self.exponent(*v) }
+    fn var_pow(v: &usize, e: u32) -> Self {
+        let mut exps = [0; N];
+        exps[*v] = e;
+        Self::new(exps)
+    }
+
+    #[inline] fn degree(&self) -> u32 { PackedMonomial::degree(self) }
+    #[inline] fn divides(&self, rhs: &Self) -> bool { PackedMonomial::divides(self, rhs) }
+    #[inline] fn checked_div(&self, rhs: &Self) -> Option<Self> { PackedMonomial::checked_div(self, rhs) }
+    #[inline] fn lcm(&self, rhs: &Self) -> Self { PackedMonomial::lcm(self, rhs) }
+    #[inline] fn gcd(&self, rhs: &Self) -> Self { PackedMonomial::gcd(self, rhs) }
+    #[inline] fn coprime(&self, rhs: &Self) -> bool { PackedMonomial::coprime(self, rhs) }
+}
+
+impl<const N: usize, W:PackedWord, O> Default for PackedMonomial<N,W,O> {
+    #[inline] fn default() -> Self { Self::from_bits(W::zero()) }
+}
+
+impl<const N: usize, W:PackedWord, O> From<[u32; N]> for PackedMonomial<N,W,O> {
+    #[inline] fn from(exps: [u32; N]) -> Self { Self::new(exps) }
+}
+
+impl<const N: usize, W:PackedWord, O> From<PackedMonomial<N,W,O>> for Monomial<usize,O> {
+    fn from(m: PackedMonomial<N,W,O>) -> Self { m.exponents().iter().copied().enumerate().collect() }
+}
+
+impl<const N: usize, W:PackedWord, O> Debug for PackedMonomial<N,W,O> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+        f.debug_tuple("PackedMonomial").field(&self.exponents()).finish()
+    }
+}
+
+//lets the fixed orders compare the packed bits directly and falls back on iterating the exponents otherwise
+trait PackedOrder<const N: usize, W:PackedWord>: MonomialOrder<usize> + Sized {
+    fn _cmp_packed(a: &PackedMonomial<N,W,Self>, b: &PackedMonomial<N,W,Self>) -> Ordering;
+}
+
+impl<const N: usize, W:PackedWord, O:MonomialOrder<usize>> PackedOrder<N,W> for O {
+    default fn _cmp_packed(a: &PackedMonomial<N,W,O>, b: &PackedMonomial<N,W,O>) -> Ordering {
+        O::cmp_monomials(a.degree() as u64, b.degree() as u64, a.zip_exponents(*b))
+    }
+}
+
+impl<const N: usize, W:PackedWord> PackedOrder<N,W> for Lex {
+    #[inline] fn _cmp_packed(a: &PackedMonomial<N,W,Lex>, b: &PackedMonomial<N,W,Lex>) -> Ordering {
+        let mask = PackedMonomial::<N,W,Lex>::var_mask();
+        (a.bits & mask).cmp(&(b.bits & mask))
+    }
+}
+
+impl<const N: usize, W:PackedWord> PackedOrder<N,W> for GrLex {
+    #[inline] fn _cmp_packed(a: &PackedMonomial<N,W,GrLex>, b: &PackedMonomial<N,W,GrLex>) -> Ordering {
+        a.bits.cmp(&b.bits)
+    }
+}
+
+impl<const N: usize, W:PackedWord> PackedOrder<N,W> for GrevLex {
+    #[inline] fn _cmp_packed(a: &PackedMonomial<N,W,GrevLex>, b: &PackedMonomial<N,W,GrevLex>) -> Ordering {
+        a.degree().cmp(&b.degree()).then_with(|| {
+            //the lowest differing field is the last variable with differing exponents
+            let diff = a.bits ^ b.bits;
+            if diff == W::zero() { return Ordering::Equal; }
+            let width = PackedMonomial::<N,W,GrevLex>::width();
+            let shift = (diff.trailing_zeros() / width * width) as usize;
+            b.field(shift).cmp(&a.field(shift))
+        })
+    }
+}
+
+impl<const N: usize, W:PackedWord, O:MonomialOrder<usize>> PartialOrd for PackedMonomial<N,W,O> {
+    #[inline] fn partial_cmp(&self, rhs:&Self) -> Option<Ordering> { Some(self.cmp(rhs)) }
+}
+
+impl<const N: usize, W:PackedWord, O:MonomialOrder<usize>> Ord for PackedMonomial<N,W,O> {
+    #[inline] fn cmp(&self, rhs:&Self) -> Ordering { O::_cmp_packed(self, rhs) }
+}
+
+impl<const N: usize, W:PackedWord, O> MulAssociative for PackedMonomial<N,W,O> {}
+impl<const N: usize, W:PackedWord, O> MulCommutative for PackedMonomial<N,W,O> {}
+
+impl<const N: usize, W:PackedWord, O> MulAssign for PackedMonomial<N,W,O> {
+    #[inline] fn mul_assign(&mut self, rhs:Self) { *self = *self * rhs }
+}
+
+impl<const N: usize, W:PackedWord, O> Mul for PackedMonomial<N,W,O> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs:Self) -> Self {
+        debug_assert!(self.checked_mul(&rhs).is_some(), "packed monomial degree overflow");
+        Self::from_bits(self.bits + rhs.bits)
+    }
+}
+
+impl<const N: usize, W:PackedWord, O> Mul for &PackedMonomial<N,W,O> {
+    type Output = PackedMonomial<N,W,O>;
+    #[inline] fn mul(self, rhs:Self) -> PackedMonomial<N,W,O> { *self * *rhs }
+}
+
+impl<const N: usize, W:PackedWord, O> One for PackedMonomial<N,W,O> {
+    #[inline] fn one() -> Self { Self::default() }
+    #[inline] fn is_one(&self) -> bool { self.bits == W::zero() }
+}
+
+impl<Z:Natural, const N: usize, W:PackedWord, O> Pow<Z> for PackedMonomial<N,W,O> {
+    type Output = Self;
+    fn pow(self, p:Z) -> Self {
+        let p = p.to_u64().filter(|p| self.degree() as u64 * p <= Self::max_degree() as u64);
+        Self::from_bits(self.bits * W::from(p.expect("exponent too large for a packed monomial")).unwrap())
+    }
+}
+
+///
+///Writes the monomial as a product of powers, eg `x0^2*x1`, or `1` if it has no variables
+///
+///If the "alternate" flag `#` is used, the `*`'s are dropped
+///
+impl<const N: usize, W:PackedWord, O> Display for PackedMonomial<N,W,O> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+        if self.is_one() { return write!(f, "1"); }
+        let mut first = true;
+        for (i, e) in self.exponents().iter().enumerate().filter(|(_,e)| **e != 0) {
+            if !first && !f.alternate() { write!(f, "*")?; }
+            first = false;
+            if *e == 1 { write!(f, "x{}", i)?; } else { write!(f, "x{}^{}", i, e)?; }
+        }
+        Ok(())
+    }
+}
+
+//write the terms of polynomials from greatest to least
+impl<const N: usize, W:PackedWord, O:MonomialOrder<usize>> SortTerms for PackedMonomial<N,W,O> {
+    fn _sort_terms<R,Q:Borrow<Self>>(terms: &mut [(&R,Q)]) { terms.sort_by(|(_,t1),(_,t2)| t2.borrow().cmp(t1.borrow())) }
+}
+
+///
+///A polynomial in `N` indexed variables with its monomials packed into integers of type `W`
+///
+///This is the same as a [Polynomial] but uses [PackedMonomial]'s for its terms, making it
+///considerably faster whenever the number of variables and degree are small enough to fit.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use num_traits::Pow;
+///use free_algebra::PackedPolynomial;
+///
+///type P = PackedPolynomial<i64, 3>;
+///
+///let x = P::var(0);
+///let y = P::var(1);
+///let z = P::var(2);
+///
+///let p = (x.clone() + y.clone() + z.clone()).pow(2u32);
+///
+///assert_eq!(p.to_string(), "x0^2 + 2*x0*x1 + x1^2 + 2*x0*x2 + 2*x1*x2 + x2^2");
+///assert_eq!(p.leading_coeff(), Some(&1));
+///assert_eq!(p.total_degree(), Some(2));
+///assert_eq!(p.degree(2), Some(2));
+///
+///```
+///
+pub type PackedPolynomial<R, const N: usize, W=u64, O=GrevLex> = ModuleString<R,PackedMonomial<N,W,O>,MulRule,BTreeMap<PackedMonomial<N,W,O>,R>>;
+
+impl<R, const N: usize, W:PackedWord, O:MonomialOrder<usize>> ModuleString<R,PackedMonomial<N,W,O>,MulRule,BTreeMap<PackedMonomial<N,W,O>,R>> {
+
+    ///Constructs the polynomial consisting of the `i`th variable
+    pub fn var(i: usize) -> Self where R:One { PackedMonomial::var(i).into() }
+
+    ///Constructs a polynomial consisting only of a constant term
+    pub fn constant(r: R) -> Self where R:One { (r, PackedMonomial::default()).into() }
+
+    ///The coefficient of the greatest term with respect to the order `O`
+    pub fn leading_coeff(&self) -> Option<&R> { self.leading_term().map(|(r,_)| r) }
+
+    ///The greatest monomial with respect to the order `O`
+    pub fn leading_monomial(&self) -> Option<&PackedMonomial<N,W,O>> { self.leading_term().map(|(_,m)| m) }
+
+    ///The greatest total degree of any term or [None] if this polynomial is zero
+    pub fn total_degree(&self) -> Option<u32> { self.iter().map(|(_,m)| m.degree()).max() }
+
+    ///The greatest exponent of the `i`th variable in any term or [None] if this polynomial is zero
+    pub fn degree(&self, i: usize) -> Option<u32> { self.iter().map(|(_,m)| m.exponent(i)).max() }
+
+    ///Converts this polynomial to use a different monomial order
+    pub fn reorder<O2:MonomialOrder<usize>>(self) -> PackedPolynomial<R,N,W,O2> where R:AddAssign {
+        self.into_iter().map(|(r, m)| (r, m.reorder())).collect()
+    }
+
+}
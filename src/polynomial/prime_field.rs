@@ -0,0 +1,157 @@
+//!
+//!Contains [Zp], the integers modulo a prime
+//!
+
+use super::*;
+
+use std::ops::{Div, DivAssign};
+
+///
+///The field of integers modulo a prime `P`, ie `GF(P)`
+///
+///Each element is stored as its least non-negative residue, and products are computed with 128-bit
+///intermediates, so any prime that fits in a [u64] may be used. However, `P` is *not* checked for
+///primality, and if it is composite, [inversion](Inv::inv) and division will give meaningless results
+///for the non-units.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::Zp;
+///
+///type F7 = Zp<7>;
+///
+///let a = F7::from(3);
+///let b = F7::from(-2);
+///
+///assert_eq!(b.value(), 5);
+///assert_eq!(a + b, F7::from(1));
+///assert_eq!(a * b, F7::from(1));
+///assert_eq!(a.inv(), b);
+///assert_eq!(F7::from(1) / a, b);
+///assert_eq!(a.to_string(), "3");
+///
+///```
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Zp<const P: u64>(u64);
+
+impl<const P: u64> Zp<P> {
+
+    ///The characteristic of this field, ie `P`
+    pub const CHARACTERISTIC: u64 = P;
+
+    ///The least non-negative integer representing this element
+    pub fn value(self) -> u64 { self.0 }
+
+    ///The integer representing this element with the least absolute value, preferring positive values
+    pub fn symmetric_value(self) -> i128 {
+        if self.0 > P/2 { self.0 as i128 - P as i128 } else { self.0 as i128 }
+    }
+
+    ///Raises this element to a natural power using repeated squaring
+    pub fn pow_u64(self, n: u64) -> Self { repeated_squaring(self, n) }
+
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty)*) => {$(
+        impl<const P: u64> From<$t> for Zp<P> {
+            #[inline] fn from(n: $t) -> Self { Zp((n as i128).rem_euclid(P as i128) as u64) }
+        }
+    )*}
+}
+impl_from_int!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 usize);
+
+impl<const P: u64> From<u128> for Zp<P> {
+    #[inline] fn from(n: u128) -> Self { Zp((n % P as u128) as u64) }
+}
+
+impl<const P: u64> Display for Zp<P> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result { Display::fmt(&self.0, f) }
+}
+
+impl<const P: u64> AddAssign for Zp<P> {
+    #[inline] fn add_assign(&mut self, rhs: Self) { *self = Zp(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64) }
+}
+impl<const P: u64> SubAssign for Zp<P> {
+    #[inline] fn sub_assign(&mut self, rhs: Self) { *self += -rhs }
+}
+impl<const P: u64> MulAssign for Zp<P> {
+    #[inline] fn mul_assign(&mut self, rhs: Self) { *self = Zp((self.0 as u128 * rhs.0 as u128 % P as u128) as u64) }
+}
+impl<const P: u64> DivAssign for Zp<P> {
+    #[inline] fn div_assign(&mut self, rhs: Self) { self.mul_assign(rhs.inv()) }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Self;
+    #[inline] fn neg(self) -> Self { if self.0==0 { self } else { Zp(P - self.0) } }
+}
+
+///
+///The multiplicative inverse, found using Fermat's little theorem
+///
+///## Panics
+///If `self` is zero
+///
+impl<const P: u64> Inv for Zp<P> {
+    type Output = Self;
+    fn inv(self) -> Self {
+        if self.0==0 { panic!("attempted to invert zero in Z/{}Z", P); }
+        self.pow_u64(P-2)
+    }
+}
+
+impl<const P: u64> Zero for Zp<P> {
+    #[inline] fn zero() -> Self { Zp(0) }
+    #[inline] fn is_zero(&self) -> bool { self.0==0 }
+}
+impl<const P: u64> One for Zp<P> {
+    #[inline] fn one() -> Self { Zp(1 % P) }
+}
+
+impl_field_like!(impl[const P: u64] for Zp<P>);
+
+//
+//A computation over `Zp<P>` for a prime `P` only chosen at runtime from one of the lists below
+//
+pub(super) trait WithPrime {
+    type Output;
+    fn run<const P: u64>(self) -> Self::Output;
+}
+
+macro_rules! primes {
+    (small: $($s:literal)*; large: $($l:literal)*;) => {
+
+        pub(super) const SMALL_PRIMES: &[u64] = &[$($s),*];
+        pub(super) const LARGE_PRIMES: &[u64] = &[$($l),*];
+
+        //runs f with P set to p, which must be one of the primes listed above
+        pub(super) fn with_prime<F:WithPrime>(p: u64, f: F) -> F::Output {
+            match p {
+                $($s => f.run::<$s>(),)*
+                $($l => f.run::<$l>(),)*
+                _ => panic!("{} is not a listed prime", p),
+            }
+        }
+
+    }
+}
+
+//
+//The primes used by the modular factoring and GCD algorithms
+//
+//The small primes are the odd primes below 256, which keep factoring modulo p cheap while still
+//giving a choice of several that preserve a polynomial's degree and square-freeness. The large
+//primes are the six largest below 2^62, found by testing downwards from 2^62, so that
+//residues and their sums stay well within an i64 while the product of any two fits in an i128.
+//
+primes!(
+    small:
+        3 5 7 11 13 17 19 23 29 31 37 41 43 47 53 59 61 67 71 73 79 83 89 97 101 103 107 109 113 127
+        131 137 139 149 151 157 163 167 173 179 181 191 193 197 199 211 223 227 229 233 239 241 251;
+    large:
+        4611686018427387847 4611686018427387817 4611686018427387787
+        4611686018427387761 4611686018427387751 4611686018427387737;
+);
@@ -0,0 +1,279 @@
+//!
+//!Contains [QuotientRing], the polynomials modulo a fixed polynomial
+//!
+
+use super::*;
+use super::univariate::write_poly;
+
+use std::fmt::Debug;
+use std::ops::{Div, DivAssign};
+
+///
+///A monic polynomial `f` used as the modulus of a [QuotientRing] `R[x]/(f)`
+///
+///Like the [rules](MulRule) of the monoid rings, the modulus is given by a type so that it is
+///known wherever the elements are constructed, including by [Zero] and [One].
+///
+///# Examples
+///```
+///use free_algebra::{Modulus, UniPoly, Rational};
+///
+///pub struct Sqrt2;
+///impl Modulus<Rational> for Sqrt2 {
+///    fn modulus() -> UniPoly<Rational> { UniPoly::from(vec![-2, 0, 1]).map_coeffs(Rational::from) }
+///    fn variable() -> &'static str { "√2" }
+///}
+///
+///```
+///
+pub trait Modulus<R> {
+    ///The modulus, which must be monic of positive degree
+    fn modulus() -> UniPoly<R>;
+
+    ///The name used for the image of `x` when formatting, `"a"` by default
+    fn variable() -> &'static str { "a" }
+}
+
+///
+///The ring `R[x]/(f)` of univariate polynomials modulo the [modulus](Modulus) `f` given by `M`
+///
+///Each element is stored as its remainder modulo `f`, ie as a polynomial of degree less than `deg(f)`
+///in the image `a` of `x`, and every product is reduced using [pseudo-division](UniPoly::pseudo_div_rem),
+///which is exact since `f` is monic. Thus, this works over any commutative ring, eg, giving
+///`Z[√2]` or the cyclotomic integers.
+///
+///When `R` is a field and `f` is irreducible, `R[x]/(f)` is a field too, with inverses found using
+///the [extended Euclidean algorithm](UniPoly::extended_gcd). In particular, this gives the
+///algebraic number fields over the [rationals](Rational) and the finite fields `GF(p^n)` over
+///the [prime fields](Zp). However, as with [Zp], `f` is *not* checked for irreducibility, so
+///[inversion](Inv::inv) panics on any element sharing a factor with `f`.
+///
+///In addition to the ring operations, there is also the [norm](QuotientRing::norm) and
+///[trace](QuotientRing::trace) of the extension.
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::{Modulus, QuotientRing, UniPoly, Rational};
+///
+///pub struct Sqrt2;
+///impl Modulus<Rational> for Sqrt2 {
+///    fn modulus() -> UniPoly<Rational> { UniPoly::from(vec![-2, 0, 1]).map_coeffs(Rational::from) }
+///    fn variable() -> &'static str { "√2" }
+///}
+///
+///type QSqrt2 = QuotientRing<Rational, Sqrt2>;
+///
+///let r = |n: i64| QSqrt2::from(Rational::from(n));
+///let sqrt2 = QSqrt2::generator();
+///let a = r(1) + sqrt2.clone();
+///
+///assert_eq!(sqrt2.clone() * sqrt2.clone(), r(2));
+///assert_eq!((a.clone() * a.clone()).to_string(), "2*√2 + 3");
+///assert_eq!(a.clone().inv().to_string(), "√2 - 1");
+///assert_eq!(r(1) / (r(1) + sqrt2.clone() * r(2)), (sqrt2 * r(2) - r(1)) / r(7));
+///
+/////the norm a₀² - 2a₁² and trace 2a₀
+///assert_eq!(a.norm(), Rational::from(-1));
+///assert_eq!(a.trace(), Rational::from(2));
+///
+///```
+///
+///Over a [prime field](Zp), an irreducible modulus gives a finite field that can be used
+///as the coefficients of any other polynomial:
+///
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::{Modulus, QuotientRing, UniPoly, Polynomial, Zp};
+///
+/////GF(9) as GF(3)[i] where i² = -1
+///pub struct I;
+///impl Modulus<Zp<3>> for I {
+///    fn modulus() -> UniPoly<Zp<3>> { UniPoly::from(vec![Zp::from(1), Zp::from(0), Zp::from(1)]) }
+///    fn variable() -> &'static str { "i" }
+///}
+///type GF9 = QuotientRing<Zp<3>, I>;
+///
+///let i = GF9::generator();
+///assert_eq!(i.clone() * i.clone(), -GF9::one());
+///
+/////1 + i generates the multiplicative group of order 8
+///let g = GF9::one() + i.clone();
+///assert_eq!(g.pow(8), GF9::one());
+///assert_ne!(g.pow(4), GF9::one());
+///
+/////x² + 1 has no roots over GF(3), but factors over GF(9)
+///let x = Polynomial::<GF9>::var("x");
+///let c = |a: GF9| Polynomial::constant(a);
+///let p = (x.clone() - c(i.clone())) * (x.clone() + c(i.clone()));
+///assert_eq!(p, x.clone() * x.clone() + c(GF9::one()));
+///assert_eq!((x + c(g)).to_string(), "x + (i + 1)");
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(clone_from="true", bound="R:Clone"))]
+#[derivative(Default(bound=""))]
+#[derivative(PartialEq(bound="R:PartialEq"), Eq(bound="R:Eq"), Hash(bound="R:Hash"))]
+#[derivative(Debug(bound="R:Debug"))]
+pub struct QuotientRing<R, M:?Sized> {
+    value: UniPoly<R>,
+    #[derivative(Debug="ignore")]
+    modulus: PhantomData<M>
+}
+
+//reduces a polynomial modulo the monic polynomial given by M
+fn reduce<R:CommutativeRing, M:Modulus<R>+?Sized>(p: UniPoly<R>) -> UniPoly<R> {
+    let f = M::modulus();
+    if !f.leading_coeff().is_some_and(|c| (*c)._is_one()) {
+        panic!("attempted to reduce by a modulus that isn't monic");
+    }
+    if p.degree() < f.degree() { p } else { p.pseudo_div_rem(f).1 }
+}
+
+impl<R, M:?Sized> QuotientRing<R,M> {
+
+    ///The reduced polynomial representing this element
+    pub fn value(&self) -> &UniPoly<R> { &self.value }
+
+    ///Moves out the reduced polynomial representing this element
+    pub fn into_value(self) -> UniPoly<R> { self.value }
+
+}
+
+impl<R:CommutativeRing, M:Modulus<R>+?Sized> QuotientRing<R,M> {
+
+    ///
+    ///Constructs an element as the image of a polynomial, ie, its remainder modulo `f`
+    ///
+    ///## Panics
+    ///If the modulus isn't monic
+    ///
+    pub fn new(p: UniPoly<R>) -> Self { QuotientRing { value: reduce::<R,M>(p), modulus: PhantomData } }
+
+    ///The image `a` of `x`, ie a root of the modulus
+    pub fn generator() -> Self { Self::new(UniPoly::x()) }
+
+    ///The degree of the modulus, ie the rank of this ring over `R`
+    pub fn degree() -> usize { M::modulus().degree().unwrap_or(0) }
+
+    ///
+    ///The trace of multiplication by this element as a linear map over `R`
+    ///
+    ///This is the sum of the conjugates of this element in the splitting field of the modulus.
+    ///
+    pub fn trace(&self) -> R {
+        let n = Self::degree();
+        let (mut power, mut trace) = (self.value.clone(), R::zero());
+        for k in 0..n {
+            trace += power.coeff(k);
+            if k+1 < n { power = reduce::<R,M>(power * UniPoly::x()); }
+        }
+        trace
+    }
+
+    ///Raises this element to a natural power using repeated squaring
+    pub fn pow(&self, n: u64) -> Self { repeated_squaring(self.clone(), n) }
+
+}
+
+impl<R:IntegralDomain, M:Modulus<R>+?Sized> QuotientRing<R,M> {
+
+    ///
+    ///The determinant of multiplication by this element as a linear map over `R`
+    ///
+    ///This is the product of the conjugates of this element in the splitting field of the modulus,
+    ///and is computed as the [resultant](UniPoly::resultant) of the modulus and the representative.
+    ///Thus, it is multiplicative and is a unit exactly when this element is.
+    ///
+    pub fn norm(&self) -> R { M::modulus().resultant(self.value.clone()) }
+
+}
+
+impl<R:CommutativeRing, M:Modulus<R>+?Sized> From<R> for QuotientRing<R,M> {
+    #[inline] fn from(r: R) -> Self { Self::new(UniPoly::constant(r)) }
+}
+
+impl<R:CommutativeRing, M:Modulus<R>+?Sized> From<UniPoly<R>> for QuotientRing<R,M> {
+    #[inline] fn from(p: UniPoly<R>) -> Self { Self::new(p) }
+}
+
+///
+///Formats the reduced representative as a polynomial in the [variable](Modulus::variable) of the modulus
+///
+impl<R:Display, M:Modulus<R>+?Sized> Display for QuotientRing<R,M> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result { write_poly(f, self.value.coeffs(), M::variable(), 0) }
+}
+
+impl<R:Display, M:Modulus<R>+?Sized> Render for QuotientRing<R,M> {
+    fn is_compound(&self) -> bool { self.value.coeffs().iter().filter(|c| !(*c)._is_zero()).count() > 1 }
+}
+
+impl<R:AddAssign, M:?Sized> AddAssign for QuotientRing<R,M> {
+    fn add_assign(&mut self, rhs: Self) { self.value += rhs.value }
+}
+impl<R:SubAssign+Neg<Output=R>, M:?Sized> SubAssign for QuotientRing<R,M> {
+    fn sub_assign(&mut self, rhs: Self) { self.value -= rhs.value }
+}
+impl<R:CommutativeRing, M:Modulus<R>+?Sized> MulAssign for QuotientRing<R,M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        let lhs = ::std::mem::take(&mut self.value);
+        self.value = reduce::<R,M>(lhs * rhs.value);
+    }
+}
+impl<R:Field, M:Modulus<R>+?Sized> DivAssign for QuotientRing<R,M> {
+    fn div_assign(&mut self, rhs: Self) { self.mul_assign(rhs.inv()) }
+}
+
+impl<R:Neg<Output=R>, M:?Sized> Neg for QuotientRing<R,M> {
+    type Output = Self;
+    fn neg(self) -> Self { QuotientRing { value: -self.value, modulus: PhantomData } }
+}
+
+///
+///The multiplicative inverse, found using the extended Euclidean algorithm
+///
+///## Panics
+///If `self` shares a factor with the modulus, eg if it is zero
+///
+impl<R:Field, M:Modulus<R>+?Sized> Inv for QuotientRing<R,M> {
+    type Output = Self;
+    fn inv(self) -> Self {
+        match self.inverse() {
+            Some(inv) => inv,
+            None => panic!("attempted to invert a zero divisor of a quotient ring"),
+        }
+    }
+}
+
+impl<R:CommutativeRing, M:Modulus<R>+?Sized> Zero for QuotientRing<R,M> {
+    #[inline] fn zero() -> Self { QuotientRing { value: UniPoly::zero(), modulus: PhantomData } }
+    #[inline] fn is_zero(&self) -> bool { self.value.is_zero() }
+}
+impl<R:CommutativeRing, M:Modulus<R>+?Sized> One for QuotientRing<R,M> {
+    #[inline] fn one() -> Self { Self::new(UniPoly::constant(R::one())) }
+}
+
+impl_field_like!(@ops impl[R, M:?Sized] for QuotientRing<R,M>);
+
+impl<R:AddAssociative, M:?Sized> AddAssociative for QuotientRing<R,M> {}
+impl<R:AddCommutative, M:?Sized> AddCommutative for QuotientRing<R,M> {}
+impl<R:MulAssociative, M:?Sized> MulAssociative for QuotientRing<R,M> {}
+impl<R:MulCommutative, M:?Sized> MulCommutative for QuotientRing<R,M> {}
+impl<R:Distributive, M:?Sized> Distributive for QuotientRing<R,M> {}
+
+//these assume the modulus is irreducible, just as Zp assumes its modulus is prime
+impl<R:Field, M:Modulus<R>+?Sized> NoZeroDivisors for QuotientRing<R,M> {}
+impl<R:Field, M:Modulus<R>+?Sized> UniquelyFactorizable for QuotientRing<R,M> {}
+
+impl<R:Field, M:Modulus<R>+?Sized> Divisibility for QuotientRing<R,M> {
+    #[inline] fn divides(self, rhs: Self) -> bool { !self.is_zero() || rhs.is_zero() }
+    #[inline] fn divide(self, rhs: Self) -> Option<Self> {
+        if self.is_zero() { if rhs.is_zero() { Some(rhs) } else { None } } else { Some(rhs / self) }
+    }
+    #[inline] fn unit(&self) -> bool { self.clone().inverse().is_some() }
+    fn inverse(self) -> Option<Self> {
+        let (g, s, _) = self.value.extended_gcd(M::modulus());
+        if g.degree()==Some(0) { Some(Self::new(s)) } else { None }
+    }
+}
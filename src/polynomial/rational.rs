@@ -0,0 +1,177 @@
+//!
+//!Contains [Rational], fractions of integers
+//!
+
+use super::*;
+
+use std::cmp::Ordering;
+use std::ops::{Div, DivAssign};
+
+///
+///A fraction of integers of type `Z`, kept in lowest terms with a positive denominator
+///
+///Every operation reduces its result using the GCD, and sums and products cancel common factors
+///before multiplying, so overflow only occurs when the reduced result itself doesn't fit in `Z`.
+///
+///As a [field](Field) with a total order, this is the natural coefficient type for exact
+///computations like [root isolation](UniPoly::isolate_real_roots).
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::Rational;
+///
+///let a = Rational::new(6, -4);
+///let b = Rational::from(2);
+///
+///assert_eq!(a.numer(), &-3);
+///assert_eq!(a.denom(), &2);
+///assert_eq!(a + b, Rational::new(1, 2));
+///assert_eq!(a * b, Rational::from(-3));
+///assert_eq!(a.inv(), Rational::new(-2, 3));
+///assert!(a < b);
+///assert_eq!(a.floor(), -2);
+///assert_eq!(a.ceil(), -1);
+///assert_eq!(a.to_string(), "-3/2");
+///
+///```
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Rational<Z=i64> {
+    num: Z,
+    den: Z
+}
+
+//the non-negative GCD of two integers, where gcd(0,0) = 0
+pub(super) fn gcd<Z:Integer>(a: Z, b: Z) -> Z {
+    let (a, b) = (abs(a), abs(b));
+    if a.is_zero() { b } else if b.is_zero() { a } else { euclidean(a, b) }
+}
+
+fn abs<Z:Integer>(a: Z) -> Z { if a < Z::zero() { -a } else { a } }
+
+impl<Z:Integer> Rational<Z> {
+
+    ///
+    ///Constructs the fraction `num/den` in lowest terms
+    ///
+    ///## Panics
+    ///If `den` is zero
+    ///
+    pub fn new(num: Z, den: Z) -> Self {
+        if den.is_zero() { panic!("attempted to construct a fraction with a denominator of zero"); }
+        let g = gcd(num.clone(), den.clone());
+        let (num, den) = (num / g.clone(), den / g);
+        if den < Z::zero() { Rational { num: -num, den: -den } } else { Rational { num, den } }
+    }
+
+    ///The numerator in lowest terms, which carries the sign
+    pub fn numer(&self) -> &Z { &self.num }
+
+    ///The denominator in lowest terms, which is always positive
+    pub fn denom(&self) -> &Z { &self.den }
+
+    ///Splits this fraction into its numerator and denominator
+    pub fn into_parts(self) -> (Z, Z) { (self.num, self.den) }
+
+    ///Determines if the denominator is one
+    pub fn is_integer(&self) -> bool { self.den.is_one() }
+
+    ///The greatest integer less than or equal to this fraction
+    pub fn floor(&self) -> Z {
+        let (q, r) = (self.num.clone() / self.den.clone(), self.num.clone() % self.den.clone());
+        if r < Z::zero() { q - Z::one() } else { q }
+    }
+
+    ///The least integer greater than or equal to this fraction
+    pub fn ceil(&self) -> Z {
+        let (q, r) = (self.num.clone() / self.den.clone(), self.num.clone() % self.den.clone());
+        if r > Z::zero() { q + Z::one() } else { q }
+    }
+
+    ///The absolute value
+    pub fn abs(self) -> Self { Rational { num: abs(self.num), den: self.den } }
+
+    ///The nearest [f64] to this fraction
+    pub fn to_f64(&self) -> f64 {
+        self.num.to_f64().unwrap_or(f64::NAN) / self.den.to_f64().unwrap_or(f64::NAN)
+    }
+
+}
+
+impl<Z:Integer> From<Z> for Rational<Z> {
+    #[inline] fn from(n: Z) -> Self { Rational { num: n, den: Z::one() } }
+}
+
+impl<Z:Integer> PartialOrd for Rational<Z> {
+    #[inline] fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> { Some(self.cmp(rhs)) }
+}
+
+impl<Z:Integer> Ord for Rational<Z> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        //cancel the common factor of the denominators to keep the products small
+        let g = gcd(self.den.clone(), rhs.den.clone());
+        let lhs = self.num.clone() * (rhs.den.clone() / g.clone());
+        lhs.cmp(&(rhs.num.clone() * (self.den.clone() / g)))
+    }
+}
+
+impl<Z:Integer+Display> Display for Rational<Z> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+        if self.den.is_one() { write!(f, "{}", self.num) } else { write!(f, "{}/{}", self.num, self.den) }
+    }
+}
+
+impl<Z:Integer> AddAssign for Rational<Z> {
+    fn add_assign(&mut self, rhs: Self) {
+        let g = gcd(self.den.clone(), rhs.den.clone());
+        let (a, b) = (self.den.clone() / g.clone(), rhs.den / g);
+        let num = self.num.clone() * b.clone() + rhs.num * a;
+        *self = Rational::new(num, self.den.clone() * b);
+    }
+}
+
+impl<Z:Integer> SubAssign for Rational<Z> {
+    #[inline] fn sub_assign(&mut self, rhs: Self) { self.add_assign(-rhs) }
+}
+
+impl<Z:Integer> MulAssign for Rational<Z> {
+    fn mul_assign(&mut self, rhs: Self) {
+        //cancel across the fractions first so that the products are already in lowest terms
+        let (g1, g2) = (gcd(self.num.clone(), rhs.den.clone()), gcd(rhs.num.clone(), self.den.clone()));
+        if g1.is_zero() || g2.is_zero() { *self = Self::zero(); return; }
+        let num = (self.num.clone() / g1.clone()) * (rhs.num / g2.clone());
+        let den = (self.den.clone() / g2) * (rhs.den / g1);
+        *self = Rational { num, den };
+    }
+}
+
+impl<Z:Integer> DivAssign for Rational<Z> {
+    #[inline] fn div_assign(&mut self, rhs: Self) { self.mul_assign(rhs.inv()) }
+}
+
+impl<Z:Integer> Neg for Rational<Z> {
+    type Output = Self;
+    #[inline] fn neg(self) -> Self { Rational { num: -self.num, den: self.den } }
+}
+
+///
+///The reciprocal
+///
+///## Panics
+///If `self` is zero
+///
+impl<Z:Integer> Inv for Rational<Z> {
+    type Output = Self;
+    #[inline] fn inv(self) -> Self { Rational::new(self.den, self.num) }
+}
+
+impl<Z:Integer> Zero for Rational<Z> {
+    #[inline] fn zero() -> Self { Rational { num: Z::zero(), den: Z::one() } }
+    #[inline] fn is_zero(&self) -> bool { self.num.is_zero() }
+}
+impl<Z:Integer> One for Rational<Z> {
+    #[inline] fn one() -> Self { Rational { num: Z::one(), den: Z::one() } }
+}
+
+impl_field_like!(impl[Z:Integer] for Rational<Z>);
@@ -0,0 +1,346 @@
+//!
+//!Contains [RationalFunction], fractions of polynomials
+//!
+
+use super::*;
+
+use super::gcd::{CoeffGcd, ExactDiv};
+
+use std::fmt::Debug;
+use std::ops::{Div, DivAssign};
+
+//the polynomials over a given monomial type
+type Poly<R,T> = ModuleString<R,T,MulRule,BTreeMap<T,R>>;
+
+//a term a/p^k of a partial fraction decomposition
+type PartialFraction<R,T> = (Poly<R,T>, Poly<R,T>, usize);
+
+///
+///A fraction of polynomials over the [MonomialTerm] `T` kept in lowest terms
+///
+///After every operation, the numerator and denominator are divided by their [GCD](GCD), and the
+///denominator is normalized to have a leading coefficient of one over fields and a positive one over
+///the integers. Thus, every rational function has a unique representation, and with the usual
+///arithmetic, they form a [Field] so long as the coefficients are a field or [GCD domain](GCDDomain).
+///
+///In addition to the field operations, rational functions support:
+/// * [Evaluation](RationalFunction::eval) over fields
+/// * [Partial derivatives](RationalFunction::derivative) using the quotient rule
+/// * [Partial fraction decomposition](RationalFunction::partial_fractions) in one variable
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::{Polynomial, RationalFunction};
+///
+///let x = Polynomial::<i64>::var("x");
+///let one = Polynomial::<i64>::one();
+///
+///let f = RationalFunction::new(x.clone()*x.clone() - one.clone(), x.clone()*2 - one.clone()*2);
+///let g = RationalFunction::new(one.clone(), x.clone());
+///
+///assert_eq!(f.to_string(), "(x + 1)/2");
+///assert_eq!((f.clone() * g.clone()).to_string(), "(x + 1)/(2*x)");
+///assert_eq!((f.clone() + g.clone()).to_string(), "(x^2 + x + 2)/(2*x)");
+///assert_eq!((g.clone() - g.clone().inv()).to_string(), "(-x^2 + 1)/x");
+///assert_eq!(f.clone() / f, RationalFunction::one());
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound="R:Clone, T:Clone"))]
+#[derivative(PartialEq(bound="R:PartialEq"), Eq(bound="R:Eq"), Hash(bound="R:Hash, T:Hash"))]
+#[derivative(Debug(bound="R:Debug, T:Debug"))]
+pub struct RationalFunction<R, T:Ord=Monomial> {
+    num: Poly<R,T>,
+    den: Poly<R,T>
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> RationalFunction<R,T> {
+
+    ///
+    ///Constructs the fraction `num/den` in lowest terms
+    ///
+    ///## Panics
+    ///If `den` is zero
+    ///
+    pub fn new(num: Poly<R,T>, den: Poly<R,T>) -> Self {
+        if den.is_zero() { panic!("attempted to construct a fraction with a denominator of zero"); }
+        if num.is_zero() { return Self::zero(); }
+
+        let g = num.clone().gcd(den.clone());
+        let (num, den) = (num.exact_div(&g).unwrap(), den.exact_div(&g).unwrap());
+
+        //make the denominator unit normal
+        let u = den.leading_term().unwrap().0._unit_part();
+        if u._is_one() { return RationalFunction { num, den }; }
+        let normalize = |p: Poly<R,T>| p.into_iter().map(|(r,m)| (r._exact_div(u.clone()).unwrap(), m)).collect();
+        RationalFunction { num: normalize(num), den: normalize(den) }
+    }
+
+    ///The numerator in lowest terms
+    pub fn numer(&self) -> &Poly<R,T> { &self.num }
+
+    ///The denominator in lowest terms, which has a leading coefficient of one or a positive one
+    pub fn denom(&self) -> &Poly<R,T> { &self.den }
+
+    ///Splits this fraction into its numerator and denominator
+    pub fn into_parts(self) -> (Poly<R,T>, Poly<R,T>) { (self.num, self.den) }
+
+    ///Determines if the denominator is constant
+    pub fn is_polynomial(&self) -> bool { self.den.len()==1 && self.den.leading_term().unwrap().1.is_one() }
+
+    ///
+    ///The partial derivative with respect to a variable
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::{Polynomial, RationalFunction};
+    ///
+    ///let x = Polynomial::<i64>::var("x");
+    ///let y = Polynomial::<i64>::var("y");
+    ///let f = RationalFunction::new(x.clone()*y.clone(), x.clone() + y.clone());
+    ///
+    ///assert_eq!(f.derivative(&"x".to_string()).to_string(), "y^2/(x^2 + 2*x*y + y^2)");
+    ///assert_eq!(f.derivative(&"z".to_string()), RationalFunction::zero());
+    ///
+    ///```
+    ///
+    pub fn derivative(&self, v: &T::Var) -> Self {
+        let (dn, dd) = (poly_derivative(&self.num, v), poly_derivative(&self.den, v));
+        Self::new(dn*self.den.clone() - self.num.clone()*dd, self.den.clone()*self.den.clone())
+    }
+
+}
+
+//the partial derivative of a polynomial
+fn poly_derivative<R:IntegralDomain,T:MonomialTerm>(p: &Poly<R,T>, v: &T::Var) -> Poly<R,T> {
+    let x = T::var_pow(v, 1);
+    p.iter().filter_map(|(r, m)| {
+        let e = m.exponent_of(v);
+        if e==0 { None } else { Some((r.clone().mul_n(e), m.checked_div(&x).unwrap())) }
+    }).filter(|(r,_)| !r.is_zero()).collect()
+}
+
+//evaluates a polynomial using a value for each variable
+pub(super) fn poly_eval<R:UnitalRing,T:MonomialTerm,F:FnMut(&T::Var)->R>(p: &Poly<R,T>, point: &mut F) -> R {
+    p.iter().map(|(r, m)| {
+        m.variables().iter().fold(r.clone(), |acc, v| acc * repeated_squaring(point(v), m.exponent_of(v)))
+    }).fold(R::zero(), |a, b| a + b)
+}
+
+impl<R:Field+IntegralDomain+PartialEq, T:MonomialTerm> RationalFunction<R,T> {
+
+    ///
+    ///Evaluates this function at a point given by a value for each variable
+    ///
+    ///This returns [None] if the denominator vanishes at the point.
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::{Polynomial, RationalFunction};
+    ///
+    ///let x = Polynomial::<f64>::var("x");
+    ///let y = Polynomial::<f64>::var("y");
+    ///let f = RationalFunction::new(x.clone() + y.clone(), x.clone() - y.clone());
+    ///
+    ///assert_eq!(f.eval(|v| if v=="x" { 3.0 } else { 1.0 }), Some(2.0));
+    ///assert_eq!(f.eval(|_| 1.0), None);
+    ///
+    ///```
+    ///
+    pub fn eval<F:FnMut(&T::Var)->R>(&self, mut point: F) -> Option<R> {
+        let den = poly_eval(&self.den, &mut point);
+        if den.is_zero() { None } else { Some(poly_eval(&self.num, &mut point) / den) }
+    }
+
+}
+
+//uses specialization to factor denominators as completely as possible
+trait DenominatorFactors: Field {
+    //coprime monic factors and their multiplicities with the same product up to a constant
+    fn _factors(p: &UniPoly<Self>) -> Vec<(UniPoly<Self>, usize)>;
+}
+
+impl<R:Field> DenominatorFactors for R {
+    default fn _factors(p: &UniPoly<R>) -> Vec<(UniPoly<R>, usize)> { p.square_free_factorization() }
+}
+
+impl<const P: u64> DenominatorFactors for Zp<P> {
+    fn _factors(p: &UniPoly<Self>) -> Vec<(UniPoly<Self>, usize)> { p.factor().1 }
+}
+
+impl<Z:Integer> DenominatorFactors for Rational<Z> {
+    fn _factors(p: &UniPoly<Self>) -> Vec<(UniPoly<Self>, usize)> {
+        //clear the denominators and factor over the integers
+        let den = p.coeffs().iter().fold(Z::one(), |l, c| l.clone() / rational::gcd(l, c.denom().clone()) * c.denom().clone());
+        let q = p.clone().map_coeffs(|c| c.numer().clone() * (den.clone() / c.denom().clone()));
+        match q.try_factor() {
+            Some((_, factors)) => factors.into_iter().map(|(f, i)| (f.map_coeffs(Rational::from).monic(), i)).collect(),
+
+            //if the denominator is too large to factor completely, its square-free factors still work
+            None => p.square_free_factorization(),
+        }
+    }
+}
+
+impl<R:Field+IntegralDomain+PartialEq, T:MonomialTerm> RationalFunction<R,T> {
+
+    ///
+    ///Decomposes a rational function in one variable into a polynomial plus a sum of partial fractions
+    ///
+    ///This returns a polynomial part `q` and a list of terms `(a, p, k)` such that this function is
+    ///the sum of `q` and the fractions `a/p^k`, where each `p` is a monic factor of the denominator
+    ///and `a` has lower degree than `p`. The terms are grouped by factor with increasing powers.
+    ///
+    ///Over [prime fields](Zp) and the [rationals](Rational), the factors are irreducible, but over
+    ///other fields, they are only the pairwise coprime square-free factors of the denominator. This
+    ///is also the case over the rationals if the denominator is too large to [factor](UniPoly::try_factor).
+    ///
+    ///## Panics
+    ///If the numerator and denominator have more than one variable between them
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::{Polynomial, RationalFunction, Rational};
+    ///
+    ///let c = |n: i64| Polynomial::constant(Rational::from(n));
+    ///let x = Polynomial::<Rational>::var("x");
+    ///
+    /////the generating function of the sequence 1, 3, 7, 15, ...
+    ///let f = RationalFunction::new(c(1), (c(1) - x.clone()) * (c(1) - x.clone()*c(2)));
+    ///let (q, terms) = f.partial_fractions();
+    ///
+    ///assert!(q.is_zero());
+    ///let terms: Vec<_> = terms.iter().map(|(a, p, k)| format!("({})/({})^{}", a, p, k)).collect();
+    ///assert_eq!(terms, ["(1)/(x - 1)^1", "(-1)/(x - 1/2)^1"]);
+    ///
+    /////(x^4 + 1)/(x^3 - x^2) = x + 1 + 2/(x - 1) - 1/x - 1/x^2
+    ///let f = RationalFunction::new(x.clone()*x.clone()*x.clone()*x.clone() + c(1), x.clone()*x.clone()*x.clone() - x.clone()*x.clone());
+    ///let (q, terms) = f.partial_fractions();
+    ///
+    ///assert_eq!(q, x.clone() + c(1));
+    ///assert_eq!(terms, [(c(2), x.clone() - c(1), 1), (c(-1), x.clone(), 1), (c(-1), x.clone(), 2)]);
+    ///
+    ///```
+    ///
+    pub fn partial_fractions(&self) -> (Poly<R,T>, Vec<PartialFraction<R,T>>) {
+        let mut vars: Vec<T::Var> = self.num.iter().chain(self.den.iter()).flat_map(|(_,m)| m.variables()).collect();
+        vars.sort();
+        vars.dedup();
+        let v = match vars.len() {
+            0 => return (self.num.clone() * self.den.clone().inverse().unwrap(), Vec::new()),
+            1 => vars.pop().unwrap(),
+            _ => panic!("attempted to take the partial fractions of a multivariate function"),
+        };
+
+        //convert to and from dense univariate polynomials
+        let to_uni = |p: &Poly<R,T>| p.to_univariate(&v).map_coeffs(|c| c.leading_term().map_or_else(R::zero, |(r,_)| r.clone()));
+        let from_uni = |p: UniPoly<R>| Poly::from_univariate(
+            p.map_coeffs(|r| if r.is_zero() { Poly::zero() } else { Poly::from((r, T::one())) }), &v
+        );
+
+        let (num, den) = (to_uni(&self.num), to_uni(&self.den));
+        let (q, r) = num.div_rem(den.clone());
+
+        let mut terms = Vec::new();
+        for (p, k) in R::_factors(&den) {
+            //find the numerator over p^k by inverting the rest of the denominator modulo p^k
+            let pk = (0..k).fold(UniPoly::one(), |acc, _| acc * p.clone());
+            let rest = den.clone().div_rem(pk.clone()).0;
+            let s = rest.extended_gcd(pk.clone()).1;
+            let mut a = (r.clone() * s).div_rem(pk).1;
+
+            //then split the numerator into its digits in base p
+            let mut digits = Vec::with_capacity(k);
+            for j in 0..k {
+                let (quot, rem) = a.div_rem(p.clone());
+                if !rem.is_zero() { digits.push((from_uni(rem), from_uni(p.clone()), k-j)); }
+                a = quot;
+            }
+            terms.extend(digits.into_iter().rev());
+        }
+
+        (from_uni(q), terms)
+    }
+
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> From<Poly<R,T>> for RationalFunction<R,T> {
+    #[inline] fn from(p: Poly<R,T>) -> Self { RationalFunction { num: p, den: Poly::one() } }
+}
+
+///
+///Writes the numerator over the denominator, with parentheses around either if necessary
+///
+///If the denominator is one, only the numerator is written
+///
+impl<R:IntegralDomain+PartialEq+Display, T:MonomialTerm+Display> Display for RationalFunction<R,T> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result {
+        let (r, m) = self.den.leading_term().unwrap();
+        if self.den.len()==1 && m.is_one() && r.is_one() { return Display::fmt(&self.num, f); }
+
+        let simple_den = self.den.len()==1 && (m.is_one() || r.is_one() && m.variables().len()==1);
+        match (self.num.len() > 1, simple_den) {
+            (false, true) => write!(f, "{}/{}", self.num, self.den),
+            (false, false) => write!(f, "{}/({})", self.num, self.den),
+            (true, true) => write!(f, "({})/{}", self.num, self.den),
+            (true, false) => write!(f, "({})/({})", self.num, self.den),
+        }
+    }
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> AddAssign for RationalFunction<R,T> {
+    fn add_assign(&mut self, rhs: Self) {
+        //only multiply by the parts of the denominators that aren't shared
+        let g = self.den.clone().gcd(rhs.den.clone());
+        let (a, b) = (self.den.exact_div(&g).unwrap(), rhs.den.exact_div(&g).unwrap());
+        let num = self.num.clone()*b.clone() + rhs.num*a;
+        *self = Self::new(num, self.den.clone()*b);
+    }
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> SubAssign for RationalFunction<R,T> {
+    #[inline] fn sub_assign(&mut self, rhs: Self) { self.add_assign(-rhs) }
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> MulAssign for RationalFunction<R,T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        let num = ::std::mem::replace(&mut self.num, Poly::zero()) * rhs.num;
+        let den = ::std::mem::replace(&mut self.den, Poly::one()) * rhs.den;
+        *self = Self::new(num, den);
+    }
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> DivAssign for RationalFunction<R,T> {
+    #[inline] fn div_assign(&mut self, rhs: Self) { self.mul_assign(rhs.inv()) }
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> Neg for RationalFunction<R,T> {
+    type Output = Self;
+    #[inline] fn neg(self) -> Self { RationalFunction { num: -self.num, den: self.den } }
+}
+
+///
+///The reciprocal
+///
+///## Panics
+///If `self` is zero
+///
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> Inv for RationalFunction<R,T> {
+    type Output = Self;
+    #[inline] fn inv(self) -> Self { Self::new(self.den, self.num) }
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> Zero for RationalFunction<R,T> {
+    #[inline] fn zero() -> Self { RationalFunction { num: Poly::zero(), den: Poly::one() } }
+    #[inline] fn is_zero(&self) -> bool { self.num.is_zero() }
+}
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> One for RationalFunction<R,T> {
+    #[inline] fn one() -> Self { RationalFunction { num: Poly::one(), den: Poly::one() } }
+}
+
+impl_field_like!(impl[R:IntegralDomain+PartialEq, T:MonomialTerm] for RationalFunction<R,T>);
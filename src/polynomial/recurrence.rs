@@ -0,0 +1,172 @@
+//!
+//!Contains [LinearRecurrence] and the Berlekamp-Massey algorithm
+//!
+
+use super::*;
+
+///
+///A linear recurrence `aₙ = c₁aₙ₋₁ + c₂aₙ₋₂ + ... + cₗaₙ₋ₗ` along with its initial values
+///
+///These are usually found from the start of a sequence using the
+///[Berlekamp-Massey algorithm](LinearRecurrence::berlekamp_massey), and they correspond exactly to
+///the sequences with a rational [generating function](LinearRecurrence::generating_function).
+///
+///# Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::{FreeGroup, FreeInv::*, LinearRecurrence, UniPoly, Rational};
+///use std::collections::HashSet;
+///
+/////count the elements of the free group on a and b by their length as reduced words
+///let gens: Vec<FreeGroup<char>> = vec![Id('a').into(), Inv('a').into(), Id('b').into(), Inv('b').into()];
+///let mut sphere: HashSet<FreeGroup<char>> = Some(FreeGroup::one()).into_iter().collect();
+///let mut counts = Vec::new();
+///for n in 0..6 {
+///    counts.push(Rational::from(sphere.len() as i64));
+///    sphere = sphere.iter().flat_map(|w| gens.iter().map(move |g| w * g)).filter(|w| w.len()==n+1).collect();
+///}
+///
+///let rec = LinearRecurrence::berlekamp_massey(&counts);
+///assert_eq!(rec.order(), 2);
+///assert_eq!(rec.coeffs(), &[Rational::from(3), Rational::from(0)]);
+///assert_eq!(rec.initial_values(), &[Rational::from(1), Rational::from(4)]);
+///assert_eq!(rec.terms(8)[7], Rational::from(2916));
+///
+/////the generating function (1 + x)/(1 - 3x)
+///let (num, den) = rec.generating_function();
+///assert_eq!(num.to_string(), "x + 1");
+///assert_eq!(den.to_string(), "-3*x + 1");
+///
+///```
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LinearRecurrence<R> {
+    coeffs: Vec<R>,
+    initial: Vec<R>
+}
+
+impl<R:Field+PartialEq> LinearRecurrence<R> {
+
+    ///
+    ///Constructs a recurrence from its coefficients `c₁, ..., cₗ` and initial values `a₀, ..., aₗ₋₁`
+    ///
+    ///## Panics
+    ///If there is a different number of coefficients and initial values
+    ///
+    pub fn new(coeffs: Vec<R>, initial: Vec<R>) -> Self {
+        if coeffs.len() != initial.len() {
+            panic!("a recurrence of order {} needs exactly that many initial values", coeffs.len());
+        }
+        LinearRecurrence { coeffs, initial }
+    }
+
+    ///
+    ///Finds the shortest linear recurrence generating the given sequence
+    ///
+    ///The Berlekamp-Massey algorithm does this in `O(n²)` field operations. The result is only
+    ///guaranteed to be the recurrence of the whole (infinite) sequence if it has order at most half
+    ///the number of terms given, so it is best to provide at least twice as many terms as the
+    ///expected order.
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::{LinearRecurrence, Rational, Zp};
+    ///
+    /////the Fibonacci numbers
+    ///let fib: Vec<_> = [0, 1, 1, 2, 3, 5, 8, 13].iter().map(|n| Rational::from(*n)).collect();
+    ///let rec = LinearRecurrence::berlekamp_massey(&fib);
+    ///assert_eq!(rec.coeffs(), &[Rational::from(1), Rational::from(1)]);
+    ///
+    /////the method works over any field
+    ///let seq: Vec<Zp<2>> = [1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 1, 0, 1].iter().map(|n| Zp::from(*n)).collect();
+    ///let rec = LinearRecurrence::berlekamp_massey(&seq);
+    ///assert_eq!(rec.order(), 3);
+    ///assert_eq!(rec.terms(seq.len()), seq);
+    ///
+    ///```
+    ///
+    pub fn berlekamp_massey(seq: &[R]) -> Self {
+        //the connection polynomials, where the current one gives sₙ + C₁sₙ₋₁ + ... + Cₗsₙ₋ₗ = 0
+        let (mut c, mut b) = (vec![R::one()], vec![R::one()]);
+        let (mut len, mut shift, mut last) = (0, 1, R::one());
+
+        for n in 0..seq.len() {
+            //the discrepancy between the next term and what the current recurrence predicts
+            let d = (1..=len).fold(seq[n].clone(), |d, i| d + c.get(i).cloned().unwrap_or_else(R::zero) * seq[n-i].clone());
+            if d.is_zero() { shift += 1; continue; }
+
+            //correct the recurrence using the one from just before the length last changed
+            let prev = c.clone();
+            let scale = d.clone() / last.clone();
+            if c.len() < b.len() + shift { c.resize(b.len() + shift, R::zero()); }
+            for (i, x) in b.iter().enumerate() { c[i+shift] -= scale.clone() * x.clone(); }
+
+            if 2*len <= n {
+                len = n + 1 - len;
+                b = prev;
+                last = d;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+        }
+
+        c.resize(len+1, R::zero());
+        LinearRecurrence {
+            coeffs: c.into_iter().skip(1).map(|x| -x).collect(),
+            initial: seq[..len].to_vec()
+        }
+    }
+
+    ///The number of previous terms each term depends on
+    pub fn order(&self) -> usize { self.coeffs.len() }
+
+    ///The coefficients `c₁, ..., cₗ` where `aₙ = c₁aₙ₋₁ + ... + cₗaₙ₋ₗ`
+    pub fn coeffs(&self) -> &[R] { &self.coeffs }
+
+    ///The first `l` terms of the sequence
+    pub fn initial_values(&self) -> &[R] { &self.initial }
+
+    ///Computes the first `n` terms of the sequence
+    pub fn terms(&self, n: usize) -> Vec<R> {
+        let mut terms: Vec<R> = self.initial.iter().take(n).cloned().collect();
+        while terms.len() < n {
+            let k = terms.len();
+            terms.push(self.coeffs.iter().enumerate().fold(R::zero(), |acc, (i, c)| acc + c.clone() * terms[k-1-i].clone()));
+        }
+        terms
+    }
+
+    ///
+    ///The characteristic polynomial `xˡ - c₁xˡ⁻¹ - ... - cₗ`
+    ///
+    ///When its roots `rᵢ` are distinct, every term of the sequence is a linear combination of
+    ///the powers `rᵢⁿ`
+    ///
+    pub fn characteristic_polynomial(&self) -> UniPoly<R> {
+        UniPoly::new(self.coeffs.iter().rev().map(|c| -c.clone()).chain(Some(R::one())).collect())
+    }
+
+    ///
+    ///The generating function `∑aₙxⁿ` as a numerator and denominator
+    ///
+    ///The denominator is `1 - c₁x - ... - cₗxˡ`, and the numerator is the product of the denominator
+    ///and the initial values truncated to degree less than `l`. Note that if the recurrence was
+    ///given by [new](LinearRecurrence::new), these may have a common factor, but if it was found
+    ///with [berlekamp_massey](LinearRecurrence::berlekamp_massey), the fraction is in lowest terms.
+    ///
+    pub fn generating_function(&self) -> (UniPoly<R>, UniPoly<R>) {
+        let l = self.order();
+        let den = UniPoly::new(Some(R::one()).into_iter().chain(self.coeffs.iter().map(|c| -c.clone())).collect());
+
+        let mut num = vec![R::zero(); l];
+        for (i, a) in self.initial.iter().enumerate() {
+            for (j, d) in den.coeffs().iter().enumerate().take(l-i) {
+                num[i+j] += a.clone() * d.clone();
+            }
+        }
+
+        (UniPoly::new(num), den)
+    }
+
+}
@@ -0,0 +1,211 @@
+//!
+//!Contains resultants and discriminants of univariate and multivariate polynomials
+//!
+
+use super::*;
+
+use super::gcd::ExactDiv;
+
+impl<R:IntegralDomain> UniPoly<R> {
+
+    ///
+    ///The Sylvester matrix of two polynomials, whose determinant is their [resultant](UniPoly::resultant)
+    ///
+    ///For polynomials of degrees `m` and `n`, this is the `(m+n)`x`(m+n)` matrix whose first `n`
+    ///rows are the coefficients of `self` shifted by `0..n` places and whose last `m` rows are the
+    ///coefficients of `rhs` shifted by `0..m`, with coefficients listed from highest degree to lowest.
+    ///Zero polynomials are treated as having degree zero.
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let a = UniPoly::from(vec![3, 2, 1]); // x^2 + 2x + 3
+    ///let b = UniPoly::from(vec![5, 4]);    // 4x + 5
+    ///
+    ///assert_eq!(
+    ///    a.sylvester_matrix(&b),
+    ///    vec![vec![1, 2, 3], vec![4, 5, 0], vec![0, 4, 5]]
+    ///);
+    ///
+    ///```
+    ///
+    pub fn sylvester_matrix(&self, rhs: &Self) -> Vec<Vec<R>> {
+        let (m, n) = (self.degree().unwrap_or(0), rhs.degree().unwrap_or(0));
+        let mut matrix = Vec::with_capacity(m+n);
+        for (p, count) in [(self, n), (rhs, m)] {
+            for i in 0..count {
+                let mut row = vec![R::zero(); m+n];
+                for (j, c) in p.coeffs().iter().rev().enumerate() { row[i+j] = c.clone(); }
+                matrix.push(row);
+            }
+        }
+        matrix
+    }
+
+    ///
+    ///Computes the resultant of two polynomials using the subresultant pseudo-remainder sequence
+    ///
+    ///The resultant is the determinant of the [Sylvester matrix](UniPoly::sylvester_matrix), and
+    ///if `self` has leading coefficient `a` and roots `α₁,...,αₘ` in some extension, then it is
+    ///equal to `a^deg(rhs) * rhs(α₁)*...*rhs(αₘ)`. In particular, over an integral domain, it is
+    ///zero exactly when the polynomials have a common factor of positive degree.
+    ///
+    ///Unlike using the determinant directly, the subresultant algorithm only ever divides exactly,
+    ///so this works over any [integral domain](IntegralDomain), including multivariate polynomials.
+    ///
+    ///The resultant of a zero polynomial with anything is zero.
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let a = UniPoly::from(vec![-1, 0, 1]);       // x^2 - 1
+    ///let b = UniPoly::from(vec![-2, 1]);          // x - 2
+    ///let c = UniPoly::from(vec![-3, -2, 1]);      // x^2 - 2x - 3 = (x + 1)(x - 3)
+    ///
+    ///assert_eq!(a.clone().resultant(b.clone()), 3);
+    ///assert_eq!(b.clone().resultant(a.clone()), 3);
+    ///assert_eq!(a.clone().resultant(c), 0);
+    ///assert_eq!(a.resultant(UniPoly::constant(5)), 25);
+    ///
+    ///```
+    ///
+    pub fn resultant(self, rhs: Self) -> R {
+        let (mut a, mut b) = (self, rhs);
+        let (mut deg_a, mut deg_b) = match (a.degree(), b.degree()) {
+            (Some(m), Some(n)) => (m, n),
+            _ => return R::zero(),
+        };
+
+        //res(a,b) = (-1)^(deg(a)deg(b)) res(b,a)
+        let mut negate = false;
+        if deg_a < deg_b {
+            ::std::mem::swap(&mut a, &mut b);
+            ::std::mem::swap(&mut deg_a, &mut deg_b);
+            negate = deg_a % 2 == 1 && deg_b % 2 == 1;
+        }
+
+        let (mut g, mut h) = (R::one(), R::one());
+        while deg_b > 0 {
+            let delta = deg_a - deg_b;
+            if deg_a % 2 == 1 && deg_b % 2 == 1 { negate = !negate; }
+
+            let r = a.pseudo_div_rem(b.clone()).1;
+            let deg_r = match r.degree() {
+                Some(d) => d,
+                None => return R::zero(),
+            };
+
+            let d = g * repeated_squaring(h.clone(), delta as u64);
+            a = b;
+            b = UniPoly::new(r.into_coeffs().into_iter().map(|c| c._exact_div(d.clone()).unwrap()).collect());
+            deg_a = deg_b;
+            deg_b = deg_r;
+
+            g = a.leading_coeff().unwrap().clone();
+            h = match delta {
+                0 => h,
+                _ => repeated_squaring(g.clone(), delta as u64)._exact_div(repeated_squaring(h, delta as u64 - 1)).unwrap(),
+            };
+        }
+
+        //b is now a nonzero constant
+        let c = b.leading_coeff().unwrap().clone();
+        let res = match deg_a {
+            0 => R::one(),
+            _ => repeated_squaring(c, deg_a as u64)._exact_div(repeated_squaring(h, deg_a as u64 - 1)).unwrap(),
+        };
+        if negate { -res } else { res }
+    }
+
+    ///
+    ///Computes the discriminant of this polynomial
+    ///
+    ///For a polynomial of degree `n` with leading coefficient `a` and roots `α₁,...,αₙ`, this is
+    ///`a^(2n-2)` times the product of `(αᵢ - αⱼ)²` over all `i < j`, so it is zero exactly
+    ///when the polynomial has a repeated root. It is computed from the [resultant](UniPoly::resultant)
+    ///with the [derivative](UniPoly::derivative) as `(-1)^(n(n-1)/2) res(p, p') / a`.
+    ///
+    ///Constants have a discriminant of one.
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let quadratic = UniPoly::from(vec![1, 3, 2]);   // 2x^2 + 3x + 1
+    ///let cubic = UniPoly::from(vec![0, -1, 0, 1]);   // x^3 - x
+    ///let square = UniPoly::from(vec![1, -2, 1]);     // (x - 1)^2
+    ///
+    ///assert_eq!(quadratic.discriminant(), 3*3 - 4*2*1);
+    ///assert_eq!(cubic.discriminant(), 4);
+    ///assert_eq!(square.discriminant(), 0);
+    ///
+    ///```
+    ///
+    pub fn discriminant(&self) -> R {
+        let n = match self.degree() {
+            Some(n) if n > 0 => n,
+            _ => return R::one(),
+        };
+        let a = self.leading_coeff().unwrap().clone();
+        let res = self.clone().resultant(self.derivative())._exact_div(a).unwrap();
+        if (n*(n-1)/2) % 2 == 1 { -res } else { res }
+    }
+
+}
+
+impl<R:IntegralDomain+PartialEq, T:MonomialTerm> ModuleString<R,T,MulRule,BTreeMap<T,R>> {
+
+    ///
+    ///Computes the resultant of two polynomials with respect to the variable `v`
+    ///
+    ///This treats both as [univariate polynomials](ModuleString::to_univariate) in `v` with
+    ///coefficients in the other variables and takes their [resultant](UniPoly::resultant), which
+    ///eliminates `v`. So, any common zero of the two polynomials is also a zero of the result.
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::Polynomial;
+    ///
+    ///let x = Polynomial::<i32>::var("x");
+    ///let y = Polynomial::<i32>::var("y");
+    ///
+    /////the intersection of the unit circle with the line y = x
+    ///let circle = x.clone()*x.clone() + y.clone()*y.clone() - Polynomial::one();
+    ///let line = x.clone() - y.clone();
+    ///
+    ///let res = circle.resultant(&line, &"x".to_string());
+    ///assert_eq!(res, y.clone()*y.clone()*2 - Polynomial::one());
+    ///
+    ///```
+    ///
+    pub fn resultant(&self, rhs: &Self, v: &T::Var) -> Self {
+        self.to_univariate(v).resultant(rhs.to_univariate(v))
+    }
+
+    ///
+    ///Computes the discriminant of this polynomial with respect to the variable `v`
+    ///
+    ///The result is zero exactly when this polynomial has a repeated factor involving `v`.
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::Polynomial;
+    ///
+    ///let x = Polynomial::<i32>::var("x");
+    ///let b = Polynomial::<i32>::var("b");
+    ///let c = Polynomial::<i32>::var("c");
+    ///
+    ///let p = x.clone()*x.clone() + b.clone()*x.clone() + c.clone();
+    ///assert_eq!(p.discriminant(&"x".to_string()), b.clone()*b.clone() - c.clone()*4);
+    ///
+    ///```
+    ///
+    pub fn discriminant(&self, v: &T::Var) -> Self {
+        self.to_univariate(v).discriminant()
+    }
+
+}
@@ -0,0 +1,305 @@
+//!
+//!Contains the counting and isolation of real roots using Sturm sequences
+//!
+
+use super::*;
+
+//the sign of an element of an ordered field as -1, 0, or 1
+fn sign<R:Zero+PartialOrd>(x: &R) -> i8 {
+    if x.is_zero() { 0 } else if *x > R::zero() { 1 } else { -1 }
+}
+
+//counts the sign changes in a sequence, ignoring zeros
+fn variations<I:IntoIterator<Item=i8>>(signs: I) -> usize {
+    let mut last = 0;
+    let mut count = 0;
+    for s in signs.into_iter().filter(|s| *s!=0) {
+        if s != last && last != 0 { count += 1; }
+        last = s;
+    }
+    count
+}
+
+//scales a polynomial by a positive constant to keep the coefficients of a Sturm sequence small
+trait SturmScale { fn _sturm_scale(self) -> Self; }
+
+impl<R:Field+PartialOrd> SturmScale for UniPoly<R> {
+    //divide by the absolute value of the leading coefficient
+    default fn _sturm_scale(self) -> Self {
+        let lc = self.leading_coeff().unwrap().clone();
+        if lc < R::zero() { self / -lc } else { self / lc }
+    }
+}
+
+impl<Z:Integer> SturmScale for UniPoly<Rational<Z>> {
+    //for fractions, making the polynomial monic would make the denominators grow quickly, so
+    //instead, we clear the denominators and divide out the content
+    fn _sturm_scale(self) -> Self {
+        let (mut den, mut num) = (Z::one(), Z::zero());
+        for c in self.coeffs() {
+            den = den.clone() / gcd(den.clone(), c.denom().clone()) * c.denom().clone();
+            num = gcd(num, c.numer().clone());
+        }
+        self * Rational::new(den, num)
+    }
+}
+
+//the Sturm sequence with the last term divided out of each term
+//
+//this is the Sturm sequence of the square-free part up to sign, so unlike the original sequence,
+//it still counts correctly when evaluated at a multiple root
+fn square_free_sturm<R:Field+PartialOrd>(p: &UniPoly<R>) -> Vec<UniPoly<R>> {
+    let seq = p.sturm_sequence();
+    let g = seq.last().unwrap().clone();
+    seq.into_iter().map(|q| q.div_rem(g.clone()).0).collect()
+}
+
+//the number of sign changes of a Sturm sequence evaluated at a point
+fn variations_at<R:Field+PartialOrd>(seq: &[UniPoly<R>], x: &R) -> usize {
+    variations(seq.iter().map(|p| sign(&p.eval(x.clone()))))
+}
+
+impl<R:Field+PartialOrd> UniPoly<R> {
+
+    ///
+    ///Computes the Sturm sequence of this polynomial
+    ///
+    ///This starts with `p` and its derivative `p'`, and then each subsequent term is the negated
+    ///remainder of the previous two, stopping before the remainder is zero. Each term after the first
+    ///is also scaled by a positive constant, which doesn't affect the sign changes of the sequence but
+    ///keeps the coefficients small. In general, this makes the leading coefficient `±1`, but for
+    ///[Rational] coefficients, each term is instead made into a primitive integer polynomial.
+    ///
+    ///By Sturm's theorem, if `V(x)` is the number of sign changes in the sequence evaluated at `x`,
+    ///then `V(a) - V(b)` is the number of distinct real roots in the interval `(a, b]` so long as
+    ///neither `a` nor `b` is a multiple root. (Other methods here handle that case by dividing every
+    ///term by the last one.)
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::{UniPoly, Rational};
+    ///
+    ///let p = UniPoly::from(vec![-1, -1, 0, 1]).map_coeffs(Rational::from);  // x^3 - x - 1
+    ///let seq = p.sturm_sequence();
+    ///
+    ///assert_eq!(seq.len(), 4);
+    ///assert_eq!(seq[1].to_string(), "3*x^2 - 1");
+    ///assert_eq!(seq[2].to_string(), "2*x + 3");
+    ///assert_eq!(seq[3].to_string(), "-1");
+    ///
+    ///```
+    ///
+    pub fn sturm_sequence(&self) -> Vec<Self> {
+        let mut seq = vec![self.clone()];
+        if self.degree() <= Some(0) { return seq; }
+
+        let scale = <Self as SturmScale>::_sturm_scale;
+        seq.push(scale(self.derivative()));
+        loop {
+            let n = seq.len();
+            let r = seq[n-2].clone().div_rem(seq[n-1].clone()).1;
+            if r.is_zero() { return seq; }
+            seq.push(scale(-r));
+        }
+    }
+
+    ///
+    ///The number of sign changes between consecutive nonzero coefficients
+    ///
+    ///By Descartes' rule of signs, this is an upper bound on the number of positive real roots
+    ///counted with multiplicity, and the difference is always even. In particular, a result of
+    ///zero or one is exact.
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let p = UniPoly::from(vec![-1.0, 1.0, 0.0, -1.0, 1.0]); // x^4 - x^3 + x - 1
+    ///assert_eq!(p.sign_variations(), 3);
+    ///assert_eq!(UniPoly::from(vec![1.0, 1.0]).sign_variations(), 0);
+    ///
+    ///```
+    ///
+    pub fn sign_variations(&self) -> usize {
+        variations(self.coeffs().iter().map(sign))
+    }
+
+    ///
+    ///A bound `B` such that every complex root `z` satisfies `|z| < B`
+    ///
+    ///This is Cauchy's bound `1 + max |aᵢ/aₙ|` where `aₙ` is the leading coefficient.
+    ///
+    ///## Panics
+    ///If this polynomial is zero
+    ///
+    pub fn root_bound(&self) -> R {
+        let lc = self.leading_coeff().expect("attempted to bound the roots of zero").clone();
+        let ratios = self.coeffs().iter().map(|c| {
+            let r = c.clone() / lc.clone();
+            if r < R::zero() { -r } else { r }
+        });
+        ratios.fold(R::zero(), |m, r| if r > m { r } else { m }) + R::one()
+    }
+
+    ///
+    ///The number of distinct real roots
+    ///
+    ///## Panics
+    ///If this polynomial is zero
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::{UniPoly, Rational};
+    ///
+    /////(x^2 - 2)(x - 1)^2 (x^2 + 1)
+    ///let p = UniPoly::from(vec![-2, 4, -3, 2, 0, -2, 1]).map_coeffs(Rational::from);
+    ///
+    ///assert_eq!(p.count_real_roots(), 3);
+    ///assert_eq!(p.count_real_roots_between(&Rational::from(0), &Rational::from(2)), 2);
+    ///assert_eq!(p.count_real_roots_between(&Rational::from(-1), &Rational::from(1)), 1);
+    ///
+    ///```
+    ///
+    pub fn count_real_roots(&self) -> usize {
+        let n = self.degree().expect("attempted to count the roots of zero");
+        let seq = self.sturm_sequence();
+
+        //at ±infinity, the signs are those of the leading terms
+        let at_inf = |neg: bool| variations(seq.iter().map(|p| {
+            let s = sign(p.leading_coeff().unwrap());
+            if neg && p.degree().unwrap() % 2 == 1 { -s } else { s }
+        }));
+        if n == 0 { 0 } else { at_inf(true) - at_inf(false) }
+    }
+
+    ///
+    ///The number of distinct real roots in the half-open interval `(a, b]`
+    ///
+    ///## Panics
+    ///If this polynomial is zero or if `a > b`
+    ///
+    pub fn count_real_roots_between(&self, a: &R, b: &R) -> usize {
+        if self.is_zero() { panic!("attempted to count the roots of zero"); }
+        if a > b { panic!("attempted to count roots in an empty interval"); }
+        let seq = square_free_sturm(self);
+        variations_at(&seq, a) - variations_at(&seq, b)
+    }
+
+    ///
+    ///Finds disjoint intervals each containing exactly one of the distinct real roots
+    ///
+    ///The result is sorted in increasing order, and each interval `(a, b)` contains its root in
+    ///its interior with the polynomial nonzero at both endpoints. The intervals are found by
+    ///repeatedly bisecting the interval given by the [root bound](UniPoly::root_bound) and
+    ///counting the roots in each half with the [Sturm sequence](UniPoly::sturm_sequence), so
+    ///over the [rationals](Rational), this is exact.
+    ///
+    ///To shrink the intervals further, use [refine_root](UniPoly::refine_root).
+    ///
+    ///Note that with [Rational] coefficients, both the Sturm sequence and the evaluations at the
+    ///bisection points need integers much larger than the coefficients, with the latter taking
+    ///roughly `degree * (log2(bound) + precision)` bits. Thus, for higher degrees or precisions, it
+    ///may be necessary to use [i128] or a big integer type for `Z` to avoid overflow.
+    ///
+    ///## Panics
+    ///If this polynomial is zero
+    ///
+    ///## Examples
+    ///```
+    ///use maths_traits::algebra::*;
+    ///use free_algebra::{UniPoly, Rational, ModuleString, AddRule};
+    ///
+    /////2x^3 - x^2 - 6x + 3 = (2x - 1)(x^2 - 3) as a monoid ring over the exponents
+    ///let p = ModuleString::<Rational,u32,AddRule>::zero()
+    ///    + (Rational::from(2), 3) - (Rational::from(1), 2) - (Rational::from(6), 1) + (Rational::from(3), 0);
+    ///let p = UniPoly::from(p);
+    ///
+    ///let roots = p.isolate_real_roots();
+    ///assert_eq!(roots.len(), 3);
+    ///
+    ///let sqrt3 = 3f64.sqrt();
+    ///for ((a, b), x) in roots.iter().zip([-sqrt3, 0.5, sqrt3]) {
+    ///    assert!(a.to_f64() < x && x < b.to_f64());
+    ///}
+    ///
+    ///```
+    ///
+    pub fn isolate_real_roots(&self) -> Vec<(R, R)> {
+        let seq = square_free_sturm(self);
+        let two = R::one() + R::one();
+
+        //round the bound up to a power of two to keep the midpoints simple
+        let (bound, mut m) = (self.root_bound(), R::one());
+        while m < bound { m *= two.clone(); }
+        let bound = m;
+
+        let mut roots = Vec::new();
+        let (lo, hi) = (-bound.clone(), bound);
+        let (v_lo, v_hi) = (variations_at(&seq, &lo), variations_at(&seq, &hi));
+        let mut stack = vec![(lo, hi, v_lo, v_hi)];
+
+        while let Some((a, b, va, vb)) = stack.pop() {
+            match va - vb {
+                0 => (),
+                1 => roots.push((a, b)),
+                _ => {
+                    //split near the middle, avoiding any roots so the endpoints stay nonzero
+                    let mut mid = (a.clone() + b.clone()) / two.clone();
+                    while self.eval(mid.clone()).is_zero() { mid = (a.clone() + mid) / two.clone(); }
+                    let vm = variations_at(&seq, &mid);
+
+                    //push the upper half first so that the roots are found in increasing order
+                    stack.push((mid.clone(), b, vm, vb));
+                    stack.push((a, mid, va, vm));
+                },
+            }
+        }
+
+        roots
+    }
+
+    ///
+    ///Shrinks an interval containing exactly one distinct root until its width is at most `width`
+    ///
+    ///This bisects using the [Sturm sequence](UniPoly::sturm_sequence), so it works for roots of
+    ///any multiplicity. If a midpoint is found to be the root exactly, the interval `(r, r)` is
+    ///returned.
+    ///
+    ///The interval `(a, b)` should have the polynomial nonzero at its endpoints, as with the
+    ///result of [isolate_real_roots](UniPoly::isolate_real_roots).
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::{UniPoly, Rational};
+    ///
+    ///let p = UniPoly::from(vec![-2, 0, 1]).map_coeffs(Rational::<i64>::from); // x^2 - 2
+    ///let roots = p.isolate_real_roots();
+    ///
+    ///let (a, b) = p.refine_root(roots[1], &Rational::new(1, 1000000));
+    ///assert!(b - a <= Rational::new(1, 1000000));
+    ///assert!(a*a < Rational::from(2) && Rational::from(2) < b*b);
+    ///
+    /////exact rational roots are found if hit by a bisection
+    ///let q = UniPoly::from(vec![-1, 2]).map_coeffs(Rational::<i64>::from);  // 2x - 1
+    ///let root = q.isolate_real_roots()[0];
+    ///assert_eq!(q.refine_root(root, &Rational::new(1, 100)), (Rational::new(1, 2), Rational::new(1, 2)));
+    ///
+    ///```
+    ///
+    pub fn refine_root(&self, interval: (R, R), width: &R) -> (R, R) {
+        let seq = square_free_sturm(self);
+        let two = R::one() + R::one();
+
+        let (mut a, mut b) = interval;
+        let mut va = variations_at(&seq, &a);
+        while b.clone() - a.clone() > *width {
+            let mid = (a.clone() + b.clone()) / two.clone();
+            if self.eval(mid.clone()).is_zero() { return (mid.clone(), mid); }
+
+            let vm = variations_at(&seq, &mid);
+            if va > vm { b = mid; } else { a = mid; va = vm; }
+        }
+        (a, b)
+    }
+
+}
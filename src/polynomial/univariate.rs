@@ -0,0 +1,628 @@
+//!
+//!Contains [UniPoly] and the algorithms relevant to it
+//!
+
+use super::*;
+
+use std::ops::{Rem, RemAssign};
+
+///
+///A polynomial in one variable stored densely as a list of coefficients
+///
+///The coefficients are stored in order of increasing degree with no trailing zeros, so every
+///polynomial has a unique representation. Multiplication uses the same dense algorithms as
+///univariate [ModuleString]'s, so for [rings](Ring) of primitive integers, the product is computed with
+///Karatsuba's algorithm or a number-theoretic transform as appropriate.
+///
+///In addition to the ring arithmetic, [UniPoly] supports:
+/// * [Evaluation](UniPoly::eval) using Horner's rule and [composition](UniPoly::compose)
+/// * Formal [derivatives](UniPoly::derivative)
+/// * [Pseudo-division](UniPoly::pseudo_div_rem) over any commutative ring
+/// * [Euclidean division](UniPoly::div_rem), [GCDs](UniPoly::gcd), and the
+///   [extended Euclidean algorithm](UniPoly::extended_gcd) over fields. Furthermore, with these,
+///   [UniPoly] implements [EuclideanDiv], [GCD], and [Bezout], making it a [EuclideanDomain]
+/// * [Resultants](UniPoly::resultant) and [discriminants](UniPoly::discriminant) over any
+///   [integral domain](IntegralDomain)
+/// * [Square-free factorization](UniPoly::square_free_factorization) over fields and complete
+///   [factorization](UniPoly::factor) over [prime fields](Zp) and the integers
+/// * Counting and isolating real roots with [Sturm sequences](UniPoly::sturm_sequence) over ordered
+///   fields, such as the [rationals](Rational), and [refining](UniPoly::refine_root) them to any precision
+/// * [Lagrange](UniPoly::lagrange_interpolation) and [Newton](UniPoly::newton_interpolation)
+///   interpolation over fields
+///
+///Finally, a [UniPoly] can be converted to and from the monoid ring over its exponents,
+///ie a [ModuleString] using the [AddRule] over some [Natural] type.
+///
+///# Examples
+///```
+///use free_algebra::UniPoly;
+///
+///let x = UniPoly::<i32>::x();
+///let p = &x*&x - UniPoly::from(vec![1]);   // x^2 - 1
+///let q = &x - UniPoly::from(vec![1]);      // x - 1
+///
+///assert_eq!(p.coeffs(), &[-1, 0, 1]);
+///assert_eq!((&p * &q).to_string(), "x^3 - x^2 - x + 1");
+///assert_eq!(p.eval(3), 8);
+///assert_eq!(p.compose(&q), &x*&x - &x*2);
+///assert_eq!(p.derivative(), x*2);
+///
+///```
+///
+#[derive(Derivative)]
+#[derivative(Clone(clone_from="true"))]
+#[derivative(Default(bound=""))]
+#[derivative(PartialEq, Eq, Hash)]
+#[derivative(Debug="transparent")]
+pub struct UniPoly<R> {
+    coeffs: Vec<R>
+}
+
+impl<R> UniPoly<R> {
+
+    //removes any trailing zero coefficients
+    fn normalize(mut self) -> Self {
+        while self.coeffs.last().is_some_and(|c| (*c)._is_zero()) { self.coeffs.pop(); }
+        self
+    }
+
+    ///
+    ///Constructs a polynomial from its coefficients in order of increasing degree
+    ///
+    ///Any trailing zeros are removed
+    ///
+    pub fn new(coeffs: Vec<R>) -> Self { UniPoly { coeffs }.normalize() }
+
+    ///The coefficients of this polynomial in order of increasing degree without trailing zeros
+    pub fn coeffs(&self) -> &[R] { &self.coeffs }
+
+    ///Moves the coefficients of this polynomial into a [Vec] in order of increasing degree
+    pub fn into_coeffs(self) -> Vec<R> { self.coeffs }
+
+    ///
+    ///The degree of this polynomial or [None] if it is zero
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///assert_eq!(UniPoly::from(vec![1, 2, 3]).degree(), Some(2));
+    ///assert_eq!(UniPoly::from(vec![5]).degree(), Some(0));
+    ///assert_eq!(UniPoly::from(vec![0, 0]).degree(), None);
+    ///
+    ///```
+    ///
+    pub fn degree(&self) -> Option<usize> { self.coeffs.len().checked_sub(1) }
+
+    ///The coefficient of the highest degree term or [None] if this polynomial is zero
+    pub fn leading_coeff(&self) -> Option<&R> { self.coeffs.last() }
+
+    ///Clones the coefficient of the term of the given degree
+    pub fn coeff(&self, degree: usize) -> R where R:Zero+Clone {
+        self.coeffs.get(degree).map_or_else(R::zero, R::clone)
+    }
+
+    ///Constructs the polynomial `c*x^n`
+    pub fn monomial(c: R, n: usize) -> Self where R:Zero+Clone {
+        let mut coeffs = vec![R::zero(); n];
+        coeffs.push(c);
+        Self::new(coeffs)
+    }
+
+    ///Constructs the polynomial with only a constant term
+    pub fn constant(c: R) -> Self { Self::new(vec![c]) }
+
+    ///The polynomial `x`
+    pub fn x() -> Self where R:Zero+One+Clone { Self::monomial(R::one(), 1) }
+
+    ///
+    ///Applies a function to each coefficient, eg to change the coefficient ring
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::{UniPoly, Rational};
+    ///
+    ///let p = UniPoly::from(vec![1, 0, 2]).map_coeffs(|c| Rational::new(c, 2));
+    ///assert_eq!(p.to_string(), "x^2 + 1/2");
+    ///
+    ///```
+    ///
+    pub fn map_coeffs<S,F:FnMut(R)->S>(self, f: F) -> UniPoly<S> {
+        UniPoly::new(self.coeffs.into_iter().map(f).collect())
+    }
+
+    ///
+    ///Evaluates this polynomial at a point using Horner's rule
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let p = UniPoly::from(vec![1.0, -3.0, 2.0]); // 2x^2 - 3x + 1
+    ///
+    ///assert_eq!(p.eval(0.0), 1.0);
+    ///assert_eq!(p.eval(1.0), 0.0);
+    ///assert_eq!(p.eval(0.5), 0.0);
+    ///assert_eq!(p.eval(2.0), 3.0);
+    ///
+    ///```
+    ///
+    pub fn eval(&self, x: R) -> R where R:Semiring {
+        self.coeffs.iter().rev().fold(R::zero(), |acc, c| acc*x.clone() + c.clone())
+    }
+
+    ///
+    ///Substitutes another polynomial into this one using Horner's rule
+    ///
+    ///ie. computes `p(q(x))` from `p` and `q`
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let p = UniPoly::from(vec![0, 0, 1]); // x^2
+    ///let q = UniPoly::from(vec![1, 1]);    // x + 1
+    ///
+    ///assert_eq!(p.compose(&q), UniPoly::from(vec![1, 2, 1]));
+    ///assert_eq!(q.compose(&p), UniPoly::from(vec![1, 0, 1]));
+    ///
+    ///```
+    ///
+    pub fn compose(&self, q: &Self) -> Self where R:Semiring {
+        self.coeffs.iter().rev().fold(Self::zero(), |acc, c| acc*q.clone() + Self::constant(c.clone()))
+    }
+
+    ///
+    ///Computes the formal derivative of this polynomial
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let p = UniPoly::from(vec![5, 3, 0, 2]); // 2x^3 + 3x + 5
+    ///
+    ///assert_eq!(p.derivative(), UniPoly::from(vec![3, 0, 6]));
+    ///assert_eq!(p.derivative().derivative().derivative().derivative(), UniPoly::from(vec![]));
+    ///
+    ///```
+    ///
+    pub fn derivative(&self) -> Self where R:Semiring {
+        Self::new(self.coeffs.iter().enumerate().skip(1).map(|(i, c)| c.clone().mul_n(i)).collect())
+    }
+
+    ///
+    ///Divides this polynomial by another over any commutative ring without needing to divide coefficients
+    ///
+    ///Given `a` and a nonzero `b` with leading coefficient `l`, this finds `(q,r)` such that
+    ///`l^d * a = q*b + r` where `d = max(deg(a) - deg(b) + 1, 0)` and `r` has degree less than `b`.
+    ///
+    ///## Panics
+    ///If `rhs` is zero
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let a = UniPoly::from(vec![1, 0, 3]); // 3x^2 + 1
+    ///let b = UniPoly::from(vec![1, 2]);    // 2x + 1
+    ///
+    ///let (q, r) = a.clone().pseudo_div_rem(b.clone());
+    ///
+    ///assert_eq!(q, UniPoly::from(vec![-3, 6]));
+    ///assert_eq!(r, UniPoly::from(vec![7]));
+    ///assert_eq!(a * 4, q*b + r);
+    ///
+    ///```
+    ///
+    pub fn pseudo_div_rem(self, rhs: Self) -> (Self, Self) where R:CommutativeRing {
+        let deg_b = rhs.degree().expect("attempted to divide by a zero polynomial");
+        let lc = rhs.coeffs[deg_b].clone();
+
+        let mut e = match self.degree() {
+            Some(deg_a) if deg_a >= deg_b => deg_a - deg_b + 1,
+            _ => return (Self::zero(), self),
+        };
+
+        let (mut q, mut r) = (Self::zero(), self);
+        while let Some(deg_r) = r.degree().filter(|d| *d >= deg_b) {
+            let s = Self::monomial(r.coeffs[deg_r].clone(), deg_r - deg_b);
+            q = q*lc.clone() + s.clone();
+            r = r*lc.clone() - s*rhs.clone();
+            e -= 1;
+        }
+
+        //multiply by whatever power of the leading coefficient was skipped by cancellation
+        let scale = repeated_squaring(lc, e as u64);
+        (q*scale.clone(), r*scale)
+    }
+
+}
+
+impl<R:Field> UniPoly<R> {
+
+    ///
+    ///Performs Euclidean division by another polynomial
+    ///
+    ///This finds the unique `(q,r)` such that `self = q*rhs + r` and `r` has degree less than `rhs`
+    ///
+    ///## Panics
+    ///If `rhs` is zero
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let a = UniPoly::from(vec![-4.0, 0.0, -2.0, 1.0]); // x^3 - 2x^2 - 4
+    ///let b = UniPoly::from(vec![-3.0, 1.0]);            // x - 3
+    ///
+    ///let (q, r) = a.div_rem(b);
+    ///assert_eq!(q, UniPoly::from(vec![3.0, 1.0, 1.0]));
+    ///assert_eq!(r, UniPoly::from(vec![5.0]));
+    ///
+    ///```
+    ///
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let deg_b = rhs.degree().expect("attempted to divide by a zero polynomial");
+        let lc_inv = rhs.coeffs[deg_b].clone().inv();
+
+        let deg_a = match self.degree() {
+            Some(deg_a) if deg_a >= deg_b => deg_a,
+            _ => return (Self::zero(), self),
+        };
+
+        //long division, keeping the remainder's coefficients in place
+        let mut r = self.coeffs;
+        let mut q = vec![R::zero(); deg_a - deg_b + 1];
+        for i in (0..q.len()).rev() {
+            let c = r[i + deg_b].clone() * lc_inv.clone();
+            for (r_j, b_j) in r[i..=i+deg_b].iter_mut().zip(rhs.coeffs.iter()) {
+                *r_j -= c.clone() * b_j.clone();
+            }
+            r[i + deg_b] = R::zero();
+            q[i] = c;
+        }
+        r.truncate(deg_b);
+
+        (Self::new(q), Self::new(r))
+    }
+
+    ///
+    ///Divides this polynomial by its leading coefficient
+    ///
+    ///Zero is left unchanged
+    ///
+    pub fn monic(self) -> Self {
+        match self.leading_coeff().cloned() {
+            Some(lc) => self / lc,
+            None => self,
+        }
+    }
+
+    ///
+    ///Computes the monic greatest common divisor of two polynomials
+    ///
+    ///If both are zero, then so is the result
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let a = UniPoly::from(vec![-2.0, 0.0, 2.0]);  // 2x^2 - 2
+    ///let b = UniPoly::from(vec![1.0, 2.0, 1.0]);   // x^2 + 2x + 1
+    ///
+    ///assert_eq!(a.gcd(b), UniPoly::from(vec![1.0, 1.0]));
+    ///
+    ///```
+    ///
+    pub fn gcd(self, rhs: Self) -> Self {
+        let (mut a, mut b) = (self, rhs);
+        while !b.is_zero() {
+            let r = a.div_rem(b.clone()).1;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    ///
+    ///Computes the monic GCD `g` of two polynomials along with `s` and `t` such that `s*self + t*rhs = g`
+    ///
+    ///The result is returned as `(g, s, t)`
+    ///
+    ///## Examples
+    ///```
+    ///use free_algebra::UniPoly;
+    ///
+    ///let a = UniPoly::from(vec![-1.0, 0.0, 1.0]);  // x^2 - 1
+    ///let b = UniPoly::from(vec![2.0, 1.0]);        // x + 2
+    ///
+    ///let (g, s, t) = a.clone().extended_gcd(b.clone());
+    ///
+    ///assert_eq!(g, UniPoly::from(vec![1.0]));
+    ///assert_eq!(s.clone()*a + t.clone()*b, g);
+    ///assert_eq!(s, UniPoly::from(vec![1.0/3.0]));
+    ///
+    ///```
+    ///
+    pub fn extended_gcd(self, rhs: Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self, rhs);
+        let (mut s0, mut s1) = (Self::constant(R::one()), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::constant(R::one()));
+
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(r1.clone());
+            r0 = ::std::mem::replace(&mut r1, r);
+            let s = s0 - q.clone()*s1.clone();
+            s0 = ::std::mem::replace(&mut s1, s);
+            let t = t0 - q*t1.clone();
+            t0 = ::std::mem::replace(&mut t1, t);
+        }
+
+        //normalize so that the gcd is monic
+        match r0.leading_coeff().cloned() {
+            Some(lc) => (r0 / lc.clone(), s0 / lc.clone(), t0 / lc),
+            None => (r0, s0, t0),
+        }
+    }
+
+}
+
+impl<R> From<Vec<R>> for UniPoly<R> {
+    #[inline] fn from(coeffs: Vec<R>) -> Self { Self::new(coeffs) }
+}
+
+///
+///Converts from the monoid ring over some natural exponents
+///
+///## Examples
+///```
+///use maths_traits::algebra::Zero;
+///use free_algebra::{UniPoly, ModuleString, AddRule};
+///
+///let p = ModuleString::<i32,u32,AddRule>::zero() + (3, 2) + (1, 0);
+///let q = UniPoly::from(p.clone());
+///
+///assert_eq!(q, UniPoly::from(vec![1, 0, 3]));
+///assert_eq!(ModuleString::from(q), p);
+///
+///```
+///
+impl<R:Zero+Clone,N:Natural,S:TermMap<N,R>> From<ModuleString<R,N,AddRule,S>> for UniPoly<R> {
+    fn from(p: ModuleString<R,N,AddRule,S>) -> Self {
+        let mut coeffs = Vec::new();
+        for (r, n) in p {
+            let n = n.to_usize().expect("exponent too large for a dense polynomial");
+            if coeffs.len() <= n { coeffs.resize(n+1, R::zero()); }
+            coeffs[n] = r;
+        }
+        Self::new(coeffs)
+    }
+}
+
+impl<R:AddAssign,N:Natural,S:TermMap<N,R>> From<UniPoly<R>> for ModuleString<R,N,AddRule,S> {
+    fn from(p: UniPoly<R>) -> Self {
+        p.coeffs.into_iter().enumerate().map(|(i, r)| (r, N::from_usize(i).expect("degree too large for the exponent type"))).collect()
+    }
+}
+
+///
+///Formats the polynomial in terms of `x` from the highest degree term to the lowest
+///
+///As with [ModuleString], coefficients of `1` are omitted, negative terms are subtracted, and the
+///"alternate" flag `#` drops the `*`'s between the coefficients and powers of `x`.
+///
+///## Examples
+///```
+///use free_algebra::UniPoly;
+///
+///let p = UniPoly::from(vec![-1.5, 0.0, 1.0, 2.0]);
+///
+///assert_eq!(format!("{}", p), "2*x^3 + x^2 - 1.5");
+///assert_eq!(format!("{:#.1}", p), "2.0x^3 + x^2 - 1.5");
+///assert_eq!(format!("{}", UniPoly::<f32>::from(vec![])), "0");
+///
+///```
+///
+impl<R:Display> Display for UniPoly<R> {
+    fn fmt(&self, f: &mut Formatter) -> ::std::fmt::Result { write_poly(f, &self.coeffs, "x", 0) }
+}
+
+//formats the coefficients as a polynomial in the given variable from the highest degree term to the lowest
+//where the first coefficient is that of the given degree
+pub(super) fn write_poly<R:Display>(f: &mut Formatter, coeffs: &[R], var: &str, low: i32) -> ::std::fmt::Result {
+
+    //writes a coefficient using the flags of f and adds parentheses around sums
+    fn write_coeff<X:Display>(f: &mut Formatter, x:&X) -> ::std::fmt::Result {
+        let (l, r) = if x.is_compound() { ("(", ")") } else { ("", "") };
+        match (f.alternate(), f.precision()) {
+            (false, None) => write!(f, "{}{}{}", l, x, r),
+            (true, None) => write!(f, "{}{:#}{}", l, x, r),
+            (false, Some(p)) => write!(f, "{}{:.*}{}", l, p, x, r),
+            (true, Some(p)) => write!(f, "{}{:#.*}{}", l, p, x, r),
+        }
+    }
+
+    if coeffs.is_empty() {
+        return match R::_zero() {
+            Some(zero) => write_coeff(f, &zero),
+            None => write!(f, "{}", 0),
+        };
+    }
+
+    let terms = coeffs.iter().enumerate().rev().filter(|(_, c)| !(*c)._is_zero());
+    for (i, (n, c)) in terms.map(|(n, c)| (n as i32 + low, c)).enumerate() {
+
+        //add the sign to the previous term, using subtraction for negative coefficients
+        let abs = c._abs_if_negative();
+        match (i, abs.is_some()) {
+            (0, false) => (),
+            (0, true) => write!(f, "-")?,
+            (_, false) => write!(f, " + ")?,
+            (_, true) => write!(f, " - ")?,
+        }
+        let c = abs.as_ref().unwrap_or(c);
+
+        if n == 0 {
+            write_coeff(f, c)?;
+        } else {
+            if !c._is_one() {
+                write_coeff(f, c)?;
+                if !f.alternate() { write!(f, "*")?; }
+            }
+            if n == 1 { write!(f, "{}", var)?; } else { write!(f, "{}^{}", var, n)?; }
+        }
+    }
+
+    Ok(())
+}
+
+impl<R:AddAssociative> AddAssociative for UniPoly<R> {}
+impl<R:AddCommutative> AddCommutative for UniPoly<R> {}
+impl<R:MulAssociative> MulAssociative for UniPoly<R> {}
+impl<R:MulCommutative> MulCommutative for UniPoly<R> {}
+impl<R:Distributive> Distributive for UniPoly<R> {}
+impl<R:NoZeroDivisors> NoZeroDivisors for UniPoly<R> {}
+impl<R:Field> UniquelyFactorizable for UniPoly<R> {}
+
+impl<R:AddAssign> AddAssign for UniPoly<R> {
+    fn add_assign(&mut self, rhs:Self) {
+        let mut rhs = rhs.coeffs.into_iter();
+        for (c, r) in self.coeffs.iter_mut().zip(rhs.by_ref()) { *c += r; }
+        self.coeffs.extend(rhs);
+        *self = ::std::mem::take(self).normalize();
+    }
+}
+
+impl<R:SubAssign+Neg<Output=R>> SubAssign for UniPoly<R> {
+    fn sub_assign(&mut self, rhs:Self) {
+        let mut rhs = rhs.coeffs.into_iter();
+        for (c, r) in self.coeffs.iter_mut().zip(rhs.by_ref()) { *c -= r; }
+        self.coeffs.extend(rhs.map(R::neg));
+        *self = ::std::mem::take(self).normalize();
+    }
+}
+
+impl<R:Semiring> MulAssign for UniPoly<R> {
+    fn mul_assign(&mut self, rhs:Self) {
+        *self = Self::new(R::_dense_mul(&self.coeffs, &rhs.coeffs));
+    }
+}
+
+impl<R:MulAssign+Clone> MulAssign<R> for UniPoly<R> {
+    fn mul_assign(&mut self, rhs:R) {
+        for c in self.coeffs.iter_mut() { *c *= rhs.clone(); }
+        *self = ::std::mem::take(self).normalize();
+    }
+}
+
+impl<R:DivAssign+Clone> DivAssign<R> for UniPoly<R> {
+    fn div_assign(&mut self, rhs:R) {
+        for c in self.coeffs.iter_mut() { *c /= rhs.clone(); }
+        *self = ::std::mem::take(self).normalize();
+    }
+}
+
+impl<R:Field> DivAssign for UniPoly<R> {
+    fn div_assign(&mut self, rhs:Self) { *self = ::std::mem::take(self).div_rem(rhs).0 }
+}
+
+impl<R:Field> RemAssign for UniPoly<R> {
+    fn rem_assign(&mut self, rhs:Self) { *self = ::std::mem::take(self).div_rem(rhs).1 }
+}
+
+impl_arith!(impl<R> AddAssign<&Self>.add_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> SubAssign<&Self>.sub_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> MulAssign<&Self>.mul_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> DivAssign<&Self>.div_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> RemAssign<&Self>.rem_assign for UniPoly<R> where R:Sized);
+
+impl_arith!(impl<R> MulAssign<&R>.mul_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> DivAssign<&R>.div_assign for UniPoly<R> where R:Sized);
+
+impl_arith!(impl<R> Add.add with AddAssign.add_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> Sub.sub with SubAssign.sub_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> Mul.mul with MulAssign.mul_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> Div.div with DivAssign.div_assign for UniPoly<R> where R:Sized);
+impl_arith!(impl<R> Rem.rem with RemAssign.rem_assign for UniPoly<R> where R:Sized);
+
+impl<R:Neg<Output=R>> Neg for UniPoly<R> {
+    type Output = Self;
+    fn neg(self) -> Self { Self::new(self.coeffs.into_iter().map(R::neg).collect()) }
+}
+
+impl<R:Neg<Output=R>+Clone> Neg for &UniPoly<R> {
+    type Output = UniPoly<R>;
+    #[inline] fn neg(self) -> UniPoly<R> { -self.clone() }
+}
+
+impl<R:AddAssign> Zero for UniPoly<R> {
+    #[inline] fn zero() -> Self { Self::default() }
+    #[inline] fn is_zero(&self) -> bool { self.coeffs.is_empty() }
+}
+
+impl<R:Semiring+One+PartialEq> One for UniPoly<R> {
+    #[inline] fn one() -> Self { Self::constant(R::one()) }
+    #[inline] fn is_one(&self) -> bool { self.coeffs.len()==1 && self.coeffs[0].is_one() }
+}
+
+impl<Z:Natural,R:UnitalSemiring+PartialEq> Pow<Z> for UniPoly<R> {
+    type Output = Self;
+    fn pow(self, p:Z) -> Self { repeated_squaring(self, p) }
+}
+
+impl<R:Field> Divisibility for UniPoly<R> {
+    fn divides(self, rhs: Self) -> bool {
+        if self.is_zero() { rhs.is_zero() } else { rhs.div_rem(self).1.is_zero() }
+    }
+    fn divide(self, rhs: Self) -> Option<Self> {
+        if self.is_zero() { return None; }
+        let (q, r) = rhs.div_rem(self);
+        if r.is_zero() { Some(q) } else { None }
+    }
+    fn unit(&self) -> bool { self.degree()==Some(0) }
+    fn inverse(self) -> Option<Self> {
+        if self.unit() { Some(Self::constant(self.coeffs[0].clone().inv())) } else { None }
+    }
+}
+
+impl<R:Field> GCD for UniPoly<R> {
+    #[inline] fn gcd(self, rhs: Self) -> Self { UniPoly::gcd(self, rhs) }
+    fn lcm(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() { return Self::zero(); }
+        let g = UniPoly::gcd(self.clone(), rhs.clone());
+        (self / g * rhs).monic()
+    }
+}
+
+impl<R:Field> Bezout for UniPoly<R> {
+    fn bezout_with_gcd(self, rhs: Self) -> (Self, Self, Self) {
+        let (g, s, t) = self.extended_gcd(rhs);
+        (s, t, g)
+    }
+}
+
+///
+///Uses the [degree](UniPoly::degree) as the Euclidean norm and [div_rem](UniPoly::div_rem)
+///as the division algorithm
+///
+///## Examples
+///```
+///use maths_traits::algebra::*;
+///use free_algebra::UniPoly;
+///
+///let a = UniPoly::from(vec![6.0, 5.0, 1.0]);  // (x+2)(x+3)
+///let b = UniPoly::from(vec![8.0, 6.0, 1.0]);  // (x+2)(x+4)
+///
+///assert_eq!(euclidean(a.clone(), b.clone()).monic(), UniPoly::from(vec![2.0, 1.0]));
+///assert_eq!(a.clone().lcm(b.clone()), UniPoly::from(vec![24.0, 26.0, 9.0, 1.0]));
+///assert_eq!(a.rem_euc(b), UniPoly::from(vec![-2.0, -1.0]));
+///
+///```
+///
+impl<R:Field> EuclideanDiv for UniPoly<R> {
+    type Naturals = usize;
+    #[inline] fn euclid_norm(&self) -> usize { self.degree().unwrap_or(0) }
+    #[inline] fn div_euc(self, rhs: Self) -> Self { self.div_rem(rhs).0 }
+    #[inline] fn rem_euc(self, rhs: Self) -> Self { self.div_rem(rhs).1 }
+    #[inline] fn div_alg(self, rhs: Self) -> (Self, Self) { self.div_rem(rhs) }
+}
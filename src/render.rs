@@ -0,0 +1,507 @@
+//!
+//!Contains the [Render] trait for typesetting elements as plain text, LaTeX, or MathML
+//!
+//!For more information see the trait-level docs
+//!
+
+use super::*;
+
+use std::fmt;
+
+///A markup language that elements can be [rendered](Render) into
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Markup {
+    ///Plain text, like with [Display], but configurable
+    Text,
+    ///Inline LaTeX math, ie, what would go between a pair of `$`'s
+    Latex,
+    ///Presentation MathML, ie, what would go inside of a `<math>` element
+    MathML
+}
+
+///
+///Options for how to [render](Render) an element
+///
+///By default, factors and coefficients are simply juxtaposed, but this can be changed to any other
+///symbol using [Style::mul_symbol()] and [Style::scalar_symbol()]. Furthermore, the way powers are written
+///can be changed using [Style::collapse_powers()], [Style::unicode_exponents()], and [Style::inverses()].
+///
+///# Examples
+///```
+///use maths_traits::algebra::One;
+///use free_algebra::{FreeMonoid, Render, Style};
+///
+///let x = FreeMonoid::one() * 'a' * 'b';
+///
+///assert_eq!(x.render(&Style::latex()).to_string(), "a b");
+///assert_eq!(x.render(&Style::latex().mul_symbol("\\cdot")).to_string(), "a \\cdot b");
+///assert_eq!(
+///    x.render(&Style::mathml().mul_symbol("&#x22C5;")).to_string(),
+///    "<mrow><mi>a</mi><mo>&#x22C5;</mo><mi>b</mi></mrow>"
+///);
+///
+///```
+///
+///Using the [text](Markup::Text) markup, the same options give a more flexible alternative to
+///the [Display] impl of [MonoidalString]:
+///
+///```
+///use maths_traits::algebra::One;
+///use free_algebra::{FreeMonoid, FreeGroup, FreePow, Render, Style, Inverses};
+///use free_algebra::FreeInv::*;
+///
+///let x = FreeMonoid::one() * 'a' * 'a' * 'a' * 'b';
+///let y:FreeGroup<_> = Id('a') * Inv('b') * Inv('b') * Id('a');
+///let z = FreePow('a', 3) * FreePow('b', -1);
+///
+///let style = Style::text().collapse_powers(true);
+///assert_eq!(x.render(&style).to_string(), "a³b");
+///assert_eq!(y.render(&style).to_string(), "ab⁻²a");
+///assert_eq!(z.render(&style).to_string(), "a³b⁻¹");
+///
+///let style = style.unicode_exponents(false).mul_symbol("*");
+///assert_eq!(x.render(&style).to_string(), "a^3*b");
+///assert_eq!(y.render(&style).to_string(), "a*b^-2*a");
+///
+///let style = Style::text().inverses(Inverses::Uppercase);
+///assert_eq!(y.render(&style).to_string(), "aBBa");
+///assert_eq!(z.render(&style).to_string(), "a³B");
+///
+///```
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Style {
+    markup: Markup,
+    mul: String,
+    scalar_mul: String,
+    collapse: bool,
+    unicode: bool,
+    inverses: Inverses
+}
+
+///How a [Style] writes letters with negative exponents
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Inverses {
+    ///Writes inverses with a negative exponent, like `a⁻¹` or `a^-1`
+    Exponent,
+    ///Writes inverses by uppercasing the base, like `A` for `a⁻¹` and `A²` for `a⁻²`
+    Uppercase
+}
+
+impl Default for Style { fn default() -> Self { Self::latex() } }
+
+impl Style {
+
+    ///Creates a style for the given [Markup] that juxtaposes factors
+    pub fn new(markup: Markup) -> Self {
+        Style {
+            markup,
+            mul: String::new(),
+            scalar_mul: String::new(),
+            collapse: false,
+            unicode: true,
+            inverses: Inverses::Exponent
+        }
+    }
+
+    ///Creates a style for [plain text](Markup::Text) that juxtaposes factors
+    pub fn text() -> Self { Self::new(Markup::Text) }
+
+    ///Creates a style for [LaTeX](Markup::Latex) that juxtaposes factors
+    pub fn latex() -> Self { Self::new(Markup::Latex) }
+
+    ///Creates a style for [MathML](Markup::MathML) that juxtaposes factors
+    pub fn mathml() -> Self { Self::new(Markup::MathML) }
+
+    ///The [Markup] this style renders into
+    pub fn markup(&self) -> Markup { self.markup }
+
+    ///
+    ///Sets the symbol written between the letters of a [MonoidalString]
+    ///
+    ///An empty string (the default) juxtaposes the letters instead.
+    ///
+    pub fn mul_symbol<S:Into<String>>(mut self, symbol:S) -> Self { self.mul = symbol.into(); self }
+
+    ///
+    ///Sets the symbol written between the coefficient and term of a [ModuleString]
+    ///
+    ///An empty string (the default) juxtaposes the two instead.
+    ///
+    pub fn scalar_symbol<S:Into<String>>(mut self, symbol:S) -> Self { self.scalar_mul = symbol.into(); self }
+
+    ///
+    ///Sets whether runs of letters with the same base are combined into a single power
+    ///
+    ///For example, this would write `a*a*a*b` as `a³b` and `a⁻¹*a⁻¹` as `a⁻²`. However,
+    ///letters with non-integral exponents are never combined. The default is `false`.
+    ///
+    pub fn collapse_powers(mut self, collapse: bool) -> Self { self.collapse = collapse; self }
+
+    ///
+    ///Sets whether [plain text](Markup::Text) uses Unicode superscripts (like `a²`) or a `^` (like `a^2`)
+    ///for exponents
+    ///
+    ///The default is `true`, but a `^` is always used for exponents that have no superscript
+    ///characters. For the other markups, this has no effect.
+    ///
+    pub fn unicode_exponents(mut self, unicode: bool) -> Self { self.unicode = unicode; self }
+
+    ///Sets how letters with negative exponents are written. The default is [Inverses::Exponent].
+    pub fn inverses(mut self, inverses: Inverses) -> Self { self.inverses = inverses; self }
+
+    ///writes an infix operator like `+`
+    fn write_op(&self, f: &mut Formatter, op: &str) -> fmt::Result {
+        match self.markup {
+            Markup::Text | Markup::Latex => write!(f, " {} ", op),
+            Markup::MathML => write!(f, "<mo>{}</mo>", op),
+        }
+    }
+
+    ///writes a multiplication symbol or juxtaposes if empty
+    fn write_mul(&self, f: &mut Formatter, op: &str) -> fmt::Result {
+        match self.markup {
+            Markup::Text => write!(f, "{}", op),
+            Markup::Latex => if op.is_empty() { write!(f, " ") } else { write!(f, " {} ", op) },
+            Markup::MathML => if op.is_empty() { write!(f, "<mo>&#x2062;</mo>") } else { write!(f, "<mo>{}</mo>", op) },
+        }
+    }
+
+    fn write_prefix(&self, f: &mut Formatter, op: &str) -> fmt::Result {
+        match self.markup {
+            Markup::Text | Markup::Latex => write!(f, "{}", op),
+            Markup::MathML => write!(f, "<mo>{}</mo>", op),
+        }
+    }
+
+    fn begin_row(&self, f: &mut Formatter) -> fmt::Result {
+        match self.markup { Markup::Text | Markup::Latex => Ok(()), Markup::MathML => write!(f, "<mrow>") }
+    }
+
+    fn end_row(&self, f: &mut Formatter) -> fmt::Result {
+        match self.markup { Markup::Text | Markup::Latex => Ok(()), Markup::MathML => write!(f, "</mrow>") }
+    }
+
+    ///writes a pre-formatted symbol or number
+    fn write_atom(&self, f: &mut Formatter, atom: &str) -> fmt::Result {
+        match self.markup {
+            Markup::Text | Markup::Latex => write!(f, "{}", atom),
+            Markup::MathML => {
+                let (sign, abs) = match atom.strip_prefix('-') {
+                    Some(abs) => (true, abs),
+                    None => (false, atom)
+                };
+                let numeric = abs.starts_with(|c:char| c.is_ascii_digit() || c=='.') && abs.parse::<f64>().is_ok();
+
+                if numeric && sign {
+                    write!(f, "<mrow><mo>-</mo><mn>{}</mn></mrow>", abs)
+                } else if numeric {
+                    write!(f, "<mn>{}</mn>", abs)
+                } else {
+                    write!(f, "<mi>{}</mi>", escape_xml(atom))
+                }
+            }
+        }
+    }
+
+    ///writes a factor, adding parentheses if it is a sum
+    fn write_factor<T:Render+?Sized>(&self, f: &mut Formatter, factor: &T) -> fmt::Result {
+        if factor.is_compound() {
+            match self.markup {
+                Markup::Text => write!(f, "({})", factor.render(self)),
+                Markup::Latex => write!(f, "\\left({}\\right)", factor.render(self)),
+                Markup::MathML => write!(f, "<mrow><mo>(</mo>{}<mo>)</mo></mrow>", factor.render(self)),
+            }
+        } else {
+            write!(f, "{}", factor.render(self))
+        }
+    }
+
+    ///writes a factor, adding parentheses if it is a sum and passing along the formatting precision
+    fn write_scalar<T:Render+?Sized>(&self, f: &mut Formatter, scalar: &T) -> fmt::Result {
+        match f.precision() {
+            Some(p) => {
+                let formatted = format!("{:.*}", p, Precise(scalar, self));
+                if scalar.is_compound() {
+                    self.write_factor(f, &Verbatim(formatted))
+                } else {
+                    write!(f, "{}", formatted)
+                }
+            },
+            None => self.write_factor(f, scalar)
+        }
+    }
+
+    fn write_power(&self, f: &mut Formatter, base: &str, exp: &Power) -> fmt::Result {
+        let (base, exp) = match exp {
+            Power::Int(n) if *n<0 && self.inverses==Inverses::Uppercase => (base.to_uppercase(), n.unsigned_abs().to_string()),
+            Power::Int(n) => (base.to_string(), n.to_string()),
+            Power::Symbol(p) => (base.to_string(), p.clone()),
+        };
+        if exp=="1" { return self.write_atom(f, &base); }
+
+        match self.markup {
+            Markup::Text => {
+                match superscript(&exp) {
+                    Some(sup) if self.unicode => write!(f, "{}{}", base, sup),
+                    _ => if exp.parse::<i64>().is_ok() || exp.chars().all(char::is_alphanumeric) {
+                        write!(f, "{}^{}", base, exp)
+                    } else {
+                        write!(f, "{}^({})", base, exp)
+                    }
+                }
+            },
+            Markup::Latex => {
+                if base.chars().count() == 1 {
+                    write!(f, "{}^{{{}}}", base, exp)
+                } else {
+                    write!(f, "{{{}}}^{{{}}}", base, exp)
+                }
+            },
+            Markup::MathML => {
+                write!(f, "<msup>")?;
+                self.write_atom(f, &base)?;
+                self.write_atom(f, &exp)?;
+                write!(f, "</msup>")
+            }
+        }
+    }
+
+}
+
+///converts a string to Unicode superscripts if every character has one
+fn superscript(s: &str) -> Option<String> {
+    s.chars().map(
+        |c| match c {
+            '0' => Some('⁰'), '1' => Some('¹'), '2' => Some('²'), '3' => Some('³'), '4' => Some('⁴'),
+            '5' => Some('⁵'), '6' => Some('⁶'), '7' => Some('⁷'), '8' => Some('⁸'), '9' => Some('⁹'),
+            '+' => Some('⁺'), '-' => Some('⁻'), '=' => Some('⁼'), '(' => Some('⁽'), ')' => Some('⁾'),
+            'i' => Some('ⁱ'), 'n' => Some('ⁿ'),
+            _ => None
+        }
+    ).collect()
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+//renders a value with a precision without it being applied to anything but the value itself
+struct Precise<'a,T:?Sized>(&'a T, &'a Style);
+impl<'a,T:Render+?Sized> Display for Precise<'a,T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result { self.0.render_fmt(f, self.1) }
+}
+
+//an already rendered compound expression
+struct Verbatim(String);
+impl Render for Verbatim {
+    fn render_fmt(&self, f: &mut Formatter, _: &Style) -> fmt::Result { write!(f, "{}", self.0) }
+    fn is_compound(&self) -> bool { true }
+}
+
+///
+///Wraps a reference to an element so that it is [formatted](Display) using a [Style]
+///
+///This is created using [Render::render()]
+///
+pub struct Rendered<'a,T:?Sized> {
+    elem: &'a T,
+    style: &'a Style
+}
+
+impl<'a,T:Render+?Sized> Display for Rendered<'a,T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result { self.elem.render_fmt(f, self.style) }
+}
+
+///
+///Formats elements as configurable plain text or as a markup language like LaTeX or MathML
+///
+///While the [Display] impls of [MonoidalString] and [ModuleString] work well in a terminal, they
+///aren't what one would want to paste into a paper or a notebook, nor are they very configurable.
+///As such, this trait provides a way to typeset them using a [Style].
+///
+///In particular:
+/// * [MonoidalString]'s are written as a product of their [letters](Letter) where inverses and
+///   powers (like in [FreeGroup] and [FreePowMonoid]) are written as superscripts
+/// * [ModuleString]'s are written as a sum of their terms in a deterministic order with negative
+///   coefficients being written as subtraction
+/// * Any other [displayable](Display) type is written as a symbol or number using its [Display] impl
+///
+///Additionally, if a precision is given while formatting, it will be applied to the coefficients
+///of any [ModuleString].
+///
+///# Examples
+///```
+///use free_algebra::{Render, FreePow};
+///use free_algebra::FreeInv::*;
+///
+///let x = Id('a') * Inv('b') * Id('a');
+///let y = FreePow('x', 2) * FreePow('y', -3);
+///
+///assert_eq!(x.latex(), "a b^{-1} a");
+///assert_eq!(y.latex(), "x^{2} y^{-3}");
+///assert_eq!(
+///    x.mathml(),
+///    "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mrow>\
+///        <mi>a</mi><mo>&#x2062;</mo>\
+///        <msup><mi>b</mi><mrow><mo>-</mo><mn>1</mn></mrow></msup><mo>&#x2062;</mo>\
+///        <mi>a</mi>\
+///    </mrow></math>"
+///);
+///
+///```
+///
+///```
+///use maths_traits::algebra::One;
+///use free_algebra::{FreeAlgebra, FreeMonoid, Render, Style};
+///
+///let x:FreeMonoid<_> = 'x'.into();
+///let y:FreeMonoid<_> = 'y'.into();
+///
+///let p = FreeAlgebra::<f64,_>::one() * 2.0 - (1.5, &x*&y) + y;
+///
+///assert_eq!(p.latex(), "2 - 1.5 x y + y");
+///assert_eq!(format!("{:.2}", p.render(&Style::latex().scalar_symbol("\\cdot"))), "2.00 - 1.50 \\cdot x y + y");
+///
+///```
+///
+pub trait Render {
+
+    ///Writes this element to a [Formatter] using the markup of the given [Style]
+    fn render_fmt(&self, f: &mut Formatter, style: &Style) -> fmt::Result;
+
+    ///
+    ///Determines if this element needs parentheses when used as a factor
+    ///
+    ///By default, this is `false`, but it is `true` for a [ModuleString] with more than one term
+    ///
+    fn is_compound(&self) -> bool { false }
+
+    ///Wraps a reference to `self` so that it is [formatted](Display) using the given [Style]
+    fn render<'a>(&'a self, style: &'a Style) -> Rendered<'a,Self> { Rendered { elem: self, style } }
+
+    ///Renders `self` as inline LaTeX using the default [Style]
+    fn latex(&self) -> String { self.render(&Style::latex()).to_string() }
+
+    ///Renders `self` as a MathML `<math>` element using the default [Style]
+    fn mathml(&self) -> String {
+        format!("<math xmlns=\"http://www.w3.org/1998/Math/MathML\">{}</math>", self.render(&Style::mathml()))
+    }
+
+}
+
+impl<T:Display+?Sized> Render for T {
+    default fn render_fmt(&self, f: &mut Formatter, style: &Style) -> fmt::Result {
+        let atom = match f.precision() {
+            Some(p) => format!("{:.*}", p, self),
+            None => self.to_string()
+        };
+        style.write_atom(f, &atom)
+    }
+    default fn is_compound(&self) -> bool { false }
+}
+
+///
+///A letter of a [MonoidalString] that can be [rendered](Render) as a base raised to some exponent
+///
+///By default, any [displayable](Display) type is its own base with an exponent of `1`, but
+///[FreeInv] and [FreePow] are split up so that their inverses and powers can be typeset as superscripts
+///
+pub trait Letter {
+    ///The part of this letter that is raised to the [exponent](Letter::exponent)
+    fn base(&self) -> &dyn Display;
+    ///The power the [base](Letter::base) is raised to
+    fn exponent(&self) -> Exponent<'_>;
+}
+
+///The exponent of a [Letter]
+#[derive(Clone, Copy)]
+pub enum Exponent<'a> {
+    ///An integral power
+    Int(i64),
+    ///A symbolic power that is written using its [Display] impl
+    Symbol(&'a dyn Display)
+}
+
+impl<C:Display> Letter for C {
+    default fn base(&self) -> &dyn Display { self }
+    default fn exponent(&self) -> Exponent<'_> { Exponent::Int(1) }
+}
+
+//an owned version of an Exponent
+enum Power { Int(i64), Symbol(String) }
+
+impl<C:Display,M:?Sized> Render for MonoidalString<C,M> {
+    fn render_fmt(&self, f: &mut Formatter, style: &Style) -> fmt::Result {
+
+        //split each letter into a base and exponent, combining runs of the same base if needed
+        let mut powers: Vec<(String, Power)> = Vec::with_capacity(self.len());
+        for c in self.iter() {
+            let base = c.base().to_string();
+            let exp = match c.exponent() {
+                Exponent::Int(n) => Power::Int(n),
+                Exponent::Symbol(p) => Power::Symbol(p.to_string()),
+            };
+
+            if let (true, Some((last_base, Power::Int(m))), Power::Int(n)) = (style.collapse, powers.last_mut(), &exp) {
+                if *last_base==base {
+                    *m += n;
+                    if *m==0 { powers.pop(); }
+                    continue;
+                }
+            }
+            powers.push((base, exp));
+        }
+
+        if powers.is_empty() { return style.write_atom(f, "1"); }
+
+        if powers.len()>1 { style.begin_row(f)?; }
+        for (i, (base, exp)) in powers.iter().enumerate() {
+            if i!=0 { style.write_mul(f, &style.mul)?; }
+            style.write_power(f, base, exp)?;
+        }
+        if powers.len()>1 { style.end_row(f)?; }
+
+        Ok(())
+    }
+}
+
+impl<R:Display,T:Display,A:?Sized,S:TermMap<T,R>> Render for ModuleString<R,T,A,S> {
+    fn render_fmt(&self, f: &mut Formatter, style: &Style) -> fmt::Result {
+        if self.len()==0 {
+            //try to write 0 as formatted by R
+            return match R::_zero() {
+                Some(zero) => style.write_scalar(f, &zero),
+                None => style.write_atom(f, "0"),
+            };
+        }
+
+        style.begin_row(f)?;
+        for (i, (r, t)) in self.sorted_terms().into_iter().enumerate() {
+            let t: &T = t.borrow();
+
+            //write negative coefficients using subtraction
+            let abs = (*r)._abs_if_negative();
+            match (i, abs.is_some()) {
+                (0, false) => (),
+                (0, true) => style.write_prefix(f, "-")?,
+                (_, false) => style.write_op(f, "+")?,
+                (_, true) => style.write_op(f, "-")?,
+            }
+            let r = abs.as_ref().unwrap_or(r);
+
+            if <A as IsTOne<R,T>>::_is_t_one(t) {
+                style.write_scalar(f, r)?;
+            } else if (*r)._is_one() {
+                style.write_factor(f, t)?;
+            } else {
+                style.write_scalar(f, r)?;
+                style.write_mul(f, &style.scalar_mul)?;
+                style.write_factor(f, t)?;
+            }
+        }
+        style.end_row(f)
+    }
+
+    fn is_compound(&self) -> bool { self.len()>1 }
+}
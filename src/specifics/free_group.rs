@@ -92,6 +92,12 @@ impl<T:Eq+Display> Display for FreeInv<T> {
     }
 }
 
+///Splits a [FreeInv] into its inner `T` and an exponent of `1` or `-1`
+impl<T:Eq+Display> Letter for FreeInv<T> {
+    fn base(&self) -> &dyn Display { self.as_ref() }
+    fn exponent(&self) -> Exponent<'_> { Exponent::Int(if self.is_inv() {-1} else {1}) }
+}
+
 impl<T:Eq> FreeInv<T> {
 
     ///
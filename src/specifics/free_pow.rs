@@ -40,6 +40,16 @@ impl<C:Eq+Display,P:Display> Display for FreePow<C,P> {
     }
 }
 
+///Splits a [FreePow] into its base and exponent
+impl<C:Eq+Display,P:Display> Letter for FreePow<C,P> {
+    default fn base(&self) -> &dyn Display { &self.0 }
+    default fn exponent(&self) -> Exponent<'_> { Exponent::Symbol(&self.1) }
+}
+
+impl<C:Eq+Display,P:Display+IntegerSubset> Letter for FreePow<C,P> {
+    fn exponent(&self) -> Exponent<'_> { self.1.to_i64().map_or(Exponent::Symbol(&self.1), Exponent::Int) }
+}
+
 impl<C:Eq,P:One+Neg<Output=P>> From<FreeInv<C>> for FreePow<C,P> {
     fn from(inv: FreeInv<C>) -> Self {
         match inv {
@@ -137,3 +137,53 @@ pub type FreeModule<R,T> = ModuleString<R,T,!>;
 ///```
 ///
 pub type FreeAlgebra<R,T> = MonoidRing<R,FreeMonoid<T>>;
+
+///
+///A [MonoidRing] that stores its terms in order using a [BTreeMap]
+///
+///This only requires `M` to be [Ord] instead of [Hash], and its terms are iterated in increasing order.
+///
+pub type OrderedMonoidRing<R,M> = ModuleString<R,M,MulRule,BTreeMap<M,R>>;
+
+///
+///A [FreeModule] that stores its terms in order using a [BTreeMap]
+///
+///This only requires `T` to be [Ord] instead of [Hash], and its terms are iterated in increasing order.
+///
+///# Examples
+///```
+///use maths_traits::algebra::Zero;
+///use free_algebra::OrderedFreeModule;
+///
+///let p = OrderedFreeModule::zero() + (3.5, 'z') + (2.0, 'y') + (1.0, 'x');
+///
+///assert_eq!(p.iter().collect::<Vec<_>>(), [(&1.0, &'x'), (&2.0, &'y'), (&3.5, &'z')]);
+///assert_eq!(p.to_string(), "x + 2*y + 3.5*z");
+///
+///```
+///
+pub type OrderedFreeModule<R,T> = ModuleString<R,T,!,BTreeMap<T,R>>;
+
+///
+///A [FreeAlgebra] that stores its terms in order using a [BTreeMap]
+///
+///This only requires `T` to be [Ord] instead of [Hash], and its terms are iterated in increasing order.
+///
+pub type OrderedFreeAlgebra<R,T> = OrderedMonoidRing<R,FreeMonoid<T>>;
+
+///
+///A [FreeModule] over the indices of some basis stored densely in a [DenseMap]
+///
+///```
+///use maths_traits::algebra::Zero;
+///use free_algebra::DenseFreeModule;
+///
+///let u = DenseFreeModule::from(vec![1.0, 2.0, 0.0]);
+///let v = DenseFreeModule::zero() + (3.0, 2);
+///
+///assert_eq!((u*2.0 - v).to_vec(), [2.0, 4.0, -3.0]);
+///assert_eq!(format!("{}", DenseFreeModule::from(vec![2, 0, -3])), "2*0 - 3*2");
+///
+///```
+///
+pub type DenseFreeModule<R> = ModuleString<R,usize,!,DenseMap<R>>;